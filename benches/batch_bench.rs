@@ -0,0 +1,134 @@
+use criterion::{Criterion, Throughput, black_box, criterion_group, criterion_main};
+use fast_vec::{Vector3, batch};
+
+// Small enough to stay in L2, so the loops rather than memory bandwidth set the pace.
+const LEN: usize = 1 << 11;
+
+fn vectors() -> Vec<Vector3> {
+    (0..LEN)
+        .map(|i| {
+            let t = i as f64;
+            Vector3::new(t.sin(), t.cos() + 2.0, t * 1e-3)
+        })
+        .collect()
+}
+
+fn benchmark_add_assign_all(c: &mut Criterion) {
+    let mut group = c.benchmark_group("add_assign_all");
+    let mut vectors = vectors();
+    let offset = Vector3::new(1e-9, -1e-9, 0.0);
+
+    group.throughput(Throughput::Elements(LEN as u64));
+    group.bench_function("naive", |b| {
+        b.iter(|| vectors.iter_mut().for_each(|v| *v += black_box(offset)));
+    });
+    group.bench_function("batch", |b| {
+        b.iter(|| batch::add_assign_all(&mut vectors, black_box(offset)));
+    });
+    group.finish();
+}
+
+fn benchmark_normalize_all(c: &mut Criterion) {
+    let mut group = c.benchmark_group("normalize_all");
+    let mut vectors = vectors();
+
+    group.throughput(Throughput::Elements(LEN as u64));
+    group.bench_function("naive", |b| {
+        b.iter(|| vectors.iter_mut().for_each(|v| *v = v.normalize()));
+    });
+    group.bench_function("batch", |b| {
+        b.iter(|| batch::normalize_all(&mut vectors));
+    });
+    group.bench_function("normalize_fast_1", |b| {
+        b.iter(|| vectors.iter_mut().for_each(|v| *v = v.normalize_fast(1)));
+    });
+    group.bench_function("normalize_fast_2", |b| {
+        b.iter(|| vectors.iter_mut().for_each(|v| *v = v.normalize_fast(2)));
+    });
+    group.finish();
+}
+
+fn benchmark_dots(c: &mut Criterion) {
+    let mut group = c.benchmark_group("dots");
+    let a = vectors();
+    let b_vectors: Vec<Vector3> = a.iter().rev().copied().collect();
+    let mut out = vec![0.0; LEN];
+
+    group.throughput(Throughput::Elements(LEN as u64));
+    group.bench_function("naive", |b| {
+        b.iter(|| {
+            for ((o, a), b) in out.iter_mut().zip(&a).zip(&b_vectors) {
+                *o = a.dot(*b);
+            }
+        });
+    });
+    group.bench_function("batch", |b| {
+        b.iter(|| batch::dots(black_box(&a), &b_vectors, &mut out));
+    });
+    group.finish();
+}
+
+fn benchmark_distances_to(c: &mut Criterion) {
+    let mut group = c.benchmark_group("distances_to");
+    let vectors = vectors();
+    let point = Vector3::new(0.5, 1.0, 2.0);
+    let mut out = vec![0.0; LEN];
+
+    group.throughput(Throughput::Elements(LEN as u64));
+    group.bench_function("naive", |b| {
+        b.iter(|| {
+            for (o, v) in out.iter_mut().zip(&vectors) {
+                *o = v.distance(black_box(point));
+            }
+        });
+    });
+    group.bench_function("batch", |b| {
+        b.iter(|| batch::distances_to(&vectors, black_box(point), &mut out));
+    });
+    group.finish();
+}
+
+fn benchmark_sum(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sum");
+    let vectors = vectors();
+
+    group.throughput(Throughput::Elements(LEN as u64));
+    group.bench_function("naive", |b| {
+        b.iter(|| black_box(black_box(&vectors).iter().sum::<Vector3>()));
+    });
+    group.bench_function("batch", |b| {
+        b.iter(|| black_box(batch::sum(black_box(&vectors))));
+    });
+    group.finish();
+}
+
+fn benchmark_min_max_bounds(c: &mut Criterion) {
+    let mut group = c.benchmark_group("min_max_bounds");
+    let vectors = vectors();
+
+    group.throughput(Throughput::Elements(LEN as u64));
+    group.bench_function("naive", |b| {
+        b.iter(|| {
+            black_box(&vectors).iter().fold(
+                (Vector3::INFINITY, Vector3::NEG_INFINITY),
+                |(min, max), v| (min.min(*v), max.max(*v)),
+            )
+        });
+    });
+    group.bench_function("batch", |b| {
+        b.iter(|| batch::min_max_bounds(black_box(&vectors)));
+    });
+    group.finish();
+}
+
+criterion_group!(
+    name = benches;
+    config = Criterion::default().sample_size(50);
+    targets = benchmark_add_assign_all,
+    benchmark_normalize_all,
+    benchmark_dots,
+    benchmark_distances_to,
+    benchmark_sum,
+    benchmark_min_max_bounds
+);
+criterion_main!(benches);
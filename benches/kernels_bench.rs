@@ -0,0 +1,80 @@
+use criterion::{BatchSize, Criterion, Throughput, black_box, criterion_group, criterion_main};
+use fast_vec::kernels::{self, SimdLevel};
+use fast_vec::{Matrix4, Vector3};
+
+// Small enough to stay in L2, so the kernels rather than memory bandwidth set the pace.
+const LEN: usize = 1 << 11;
+
+fn vectors() -> Vec<Vector3> {
+    (0..LEN)
+        .map(|i| {
+            let t = i as f64;
+            Vector3::new(t.sin(), t.cos() + 2.0, t * 1e-3)
+        })
+        .collect()
+}
+
+fn levels() -> impl Iterator<Item = SimdLevel> {
+    SimdLevel::ALL
+        .into_iter()
+        .filter(|level| level.is_supported())
+}
+
+fn benchmark_dot(c: &mut Criterion) {
+    let mut group = c.benchmark_group("kernels_dot");
+    let a = vectors();
+    let b_vectors: Vec<Vector3> = a.iter().rev().copied().collect();
+    let mut out = vec![0.0; LEN];
+
+    group.throughput(Throughput::Elements(LEN as u64));
+    for level in levels() {
+        group.bench_function(format!("{level:?}"), |b| {
+            b.iter(|| kernels::dot_with(level, black_box(&a), &b_vectors, &mut out));
+        });
+    }
+    group.finish();
+}
+
+fn benchmark_normalize(c: &mut Criterion) {
+    let mut group = c.benchmark_group("kernels_normalize");
+    let vectors = vectors();
+
+    group.throughput(Throughput::Elements(LEN as u64));
+    for level in levels() {
+        group.bench_function(format!("{level:?}"), |b| {
+            b.iter_batched_ref(
+                || vectors.clone(),
+                |v| kernels::normalize_with(level, v),
+                BatchSize::LargeInput,
+            );
+        });
+    }
+    group.finish();
+}
+
+fn benchmark_transform(c: &mut Criterion) {
+    let mut group = c.benchmark_group("kernels_transform");
+    let points = vectors();
+    let matrix = Matrix4::look_at(Vector3::new(1.0, 2.0, 3.0), Vector3::ZERO, Vector3::Y).unwrap();
+
+    group.throughput(Throughput::Elements(LEN as u64));
+    for level in levels() {
+        group.bench_function(format!("{level:?}"), |b| {
+            b.iter_batched_ref(
+                || points.clone(),
+                |p| kernels::transform_with(level, black_box(&matrix), p),
+                BatchSize::LargeInput,
+            );
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    name = benches;
+    config = Criterion::default().sample_size(50);
+    targets = benchmark_dot,
+    benchmark_normalize,
+    benchmark_transform
+);
+criterion_main!(benches);
@@ -0,0 +1,85 @@
+use criterion::{BatchSize, Criterion, Throughput, black_box, criterion_group, criterion_main};
+use fast_vec::{PackedVector3, Vector3};
+
+const LEN: usize = 1 << 20;
+
+fn vectors() -> Vec<Vector3> {
+    (0..LEN)
+        .map(|i| Vector3::new(i as f64, 1.0, -(i as f64)))
+        .collect()
+}
+
+fn packed() -> Vec<PackedVector3> {
+    vectors().into_iter().map(PackedVector3::from).collect()
+}
+
+fn benchmark_stream_scale(c: &mut Criterion) {
+    let mut group = c.benchmark_group("stream_scale");
+    let vectors = vectors();
+    let packed = packed();
+
+    // Each iteration scales a fresh copy, so repeated runs don't drift the values to infinity.
+    group.throughput(Throughput::Elements(LEN as u64));
+    group.bench_function("vec3", |b| {
+        b.iter_batched_ref(
+            || vectors.clone(),
+            |vectors| {
+                for v in vectors.iter_mut() {
+                    *v *= black_box(1.0001);
+                }
+            },
+            BatchSize::LargeInput,
+        );
+    });
+    group.bench_function("packed3", |b| {
+        b.iter_batched_ref(
+            || packed.clone(),
+            |packed| {
+                for p in packed.iter_mut() {
+                    p.store(p.load() * black_box(1.0001));
+                }
+            },
+            BatchSize::LargeInput,
+        );
+    });
+    group.finish();
+}
+
+fn benchmark_stream_sum(c: &mut Criterion) {
+    let mut group = c.benchmark_group("stream_sum");
+    let vectors = vectors();
+    let packed = packed();
+
+    group.throughput(Throughput::Elements(LEN as u64));
+    group.bench_function("vec3", |b| {
+        b.iter(|| black_box(vectors.iter().sum::<Vector3>()));
+    });
+    group.bench_function("packed3", |b| {
+        b.iter(|| black_box(packed.iter().map(|p| p.load()).sum::<Vector3>()));
+    });
+    group.finish();
+}
+
+fn benchmark_bulk_conversion(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bulk_conversion");
+    let mut vectors = vectors();
+    let mut packed = packed();
+
+    group.throughput(Throughput::Elements(LEN as u64));
+    group.bench_function("load_slice", |b| {
+        b.iter(|| PackedVector3::load_slice(black_box(&packed), &mut vectors));
+    });
+    group.bench_function("store_slice", |b| {
+        b.iter(|| PackedVector3::store_slice(black_box(&vectors), &mut packed));
+    });
+    group.finish();
+}
+
+criterion_group!(
+    name = benches;
+    config = Criterion::default().sample_size(50);
+    targets = benchmark_stream_scale,
+    benchmark_stream_sum,
+    benchmark_bulk_conversion
+);
+criterion_main!(benches);
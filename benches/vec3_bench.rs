@@ -1,5 +1,5 @@
 use criterion::{Criterion, black_box, criterion_group, criterion_main};
-use fast_vec::Vector3;
+use fast_vec::{SphericalConvention, Vector3};
 use nalgebra::Vector3 as NalgebraVector3;
 
 fn benchmark_new(c: &mut Criterion) {
@@ -132,6 +132,12 @@ fn benchmark_normalize(c: &mut Criterion) {
     c.bench_function("nalgebra3_normalize", |b| {
         b.iter(|| black_box(black_box(nv).normalize()));
     });
+    c.bench_function("fast_vec3_normalize_fast_1", |b| {
+        b.iter(|| black_box(black_box(v).normalize_fast(1)));
+    });
+    c.bench_function("fast_vec3_normalize_fast_2", |b| {
+        b.iter(|| black_box(black_box(v).normalize_fast(2)));
+    });
 }
 
 fn benchmark_cross(c: &mut Criterion) {
@@ -216,6 +222,68 @@ fn benchmark_setters(c: &mut Criterion) {
     });
 }
 
+fn benchmark_from_spherical(c: &mut Criterion) {
+    let (r, azimuth, elevation) = (10.0, 0.7, -0.3);
+
+    c.bench_function("fast_vec3_from_spherical", |b| {
+        b.iter(|| {
+            black_box(Vector3::from_spherical(
+                black_box(r),
+                black_box(azimuth),
+                black_box(elevation),
+                SphericalConvention::AzimuthElevation,
+            ))
+        });
+    });
+    c.bench_function("nalgebra3_from_spherical", |b| {
+        b.iter(|| {
+            let (r, azimuth, elevation) = (black_box(r), black_box(azimuth), black_box(elevation));
+            let (sin_az, cos_az) = azimuth.sin_cos();
+            let (sin_el, cos_el) = elevation.sin_cos();
+            black_box(NalgebraVector3::new(
+                r * cos_el * cos_az,
+                r * cos_el * sin_az,
+                r * sin_el,
+            ))
+        });
+    });
+}
+
+fn benchmark_to_spherical(c: &mut Criterion) {
+    let v = Vector3::new(3.0, 4.0, 5.0);
+    let nv = NalgebraVector3::new(3.0f64, 4.0, 5.0);
+
+    c.bench_function("fast_vec3_to_spherical", |b| {
+        b.iter(|| black_box(black_box(v).to_spherical(SphericalConvention::AzimuthElevation)));
+    });
+    c.bench_function("nalgebra3_to_spherical", |b| {
+        b.iter(|| {
+            let nv = black_box(nv);
+            let rho = nv.x.hypot(nv.y);
+            black_box((nv.magnitude(), nv.y.atan2(nv.x), nv.z.atan2(rho)))
+        });
+    });
+}
+
+fn benchmark_from_cylindrical(c: &mut Criterion) {
+    c.bench_function("fast_vec3_from_cylindrical", |b| {
+        b.iter(|| {
+            black_box(Vector3::from_cylindrical(
+                black_box(2.0),
+                black_box(0.7),
+                black_box(-1.0),
+            ))
+        });
+    });
+    c.bench_function("nalgebra3_from_cylindrical", |b| {
+        b.iter(|| {
+            let (rho, phi, z) = (black_box(2.0f64), black_box(0.7f64), black_box(-1.0));
+            let (sin, cos) = phi.sin_cos();
+            black_box(NalgebraVector3::new(rho * cos, rho * sin, z))
+        });
+    });
+}
+
 criterion_group!(
     name = benches;
     config = Criterion::default().significance_level(0.1).sample_size(10_000);
@@ -234,6 +302,9 @@ criterion_group!(
     benchmark_distance,
     benchmark_distance_squared,
     benchmark_getters,
-    benchmark_setters
+    benchmark_setters,
+    benchmark_from_spherical,
+    benchmark_to_spherical,
+    benchmark_from_cylindrical
 );
 criterion_main!(benches);
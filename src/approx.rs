@@ -0,0 +1,117 @@
+#[inline]
+pub(crate) fn abs_diff_eq(a: f64, b: f64, epsilon: f64) -> bool {
+    // Equal infinities differ by NaN, so compare them directly.
+    a == b || (a - b).abs() <= epsilon
+}
+
+#[inline]
+pub(crate) fn relative_eq(a: f64, b: f64, epsilon: f64, max_relative: f64) -> bool {
+    if a == b {
+        return true;
+    }
+    if a.is_infinite() || b.is_infinite() {
+        return false;
+    }
+    let diff = (a - b).abs();
+    if diff <= epsilon {
+        return true;
+    }
+    diff <= a.abs().max(b.abs()) * max_relative
+}
+
+#[inline]
+pub(crate) fn ulps_eq(a: f64, b: f64, epsilon: f64, max_ulps: u64) -> bool {
+    if abs_diff_eq(a, b, epsilon) {
+        return true;
+    }
+    if a.is_nan() || b.is_nan() || a.is_sign_negative() != b.is_sign_negative() {
+        return false;
+    }
+    a.to_bits().abs_diff(b.to_bits()) <= max_ulps
+}
+
+/// Asserts that two vectors are exactly equal, printing the per-component error on failure.
+#[macro_export]
+macro_rules! assert_vec_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if left != right {
+                    panic!(
+                        "assertion `left == right` failed\n  left: {:?}\n right: {:?}\n error: {:?}",
+                        left,
+                        right,
+                        {
+                            let mut errors = left.to_array();
+                            for (error, b) in errors.iter_mut().zip(right.to_array()) {
+                                *error = (*error - b).abs();
+                            }
+                            errors
+                        },
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that every component of two vectors differs by at most `epsilon`
+/// (`1e-10` if omitted), printing the per-component error on failure.
+#[macro_export]
+macro_rules! assert_vec_approx_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_vec_approx_eq!($left, $right, 1e-10)
+    };
+    ($left:expr, $right:expr, $epsilon:expr $(,)?) => {
+        match (&$left, &$right, $epsilon) {
+            (left, right, epsilon) => {
+                if !left.abs_diff_eq(*right, epsilon) {
+                    panic!(
+                        "assertion `left ≈ right` failed (epsilon: {:e})\n  left: {:?}\n right: {:?}\n error: {:?}",
+                        epsilon,
+                        left,
+                        right,
+                        {
+                            let mut errors = left.to_array();
+                            for (error, b) in errors.iter_mut().zip(right.to_array()) {
+                                *error = (*error - b).abs();
+                            }
+                            errors
+                        },
+                    );
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_abs_diff_eq() {
+        assert!(abs_diff_eq(1.0, 1.0 + 1e-12, 1e-10));
+        assert!(!abs_diff_eq(1.0, 1.1, 1e-10));
+        assert!(!abs_diff_eq(f64::NAN, f64::NAN, 1.0));
+        assert!(abs_diff_eq(f64::INFINITY, f64::INFINITY, 0.0));
+        assert!(!abs_diff_eq(f64::INFINITY, f64::NEG_INFINITY, f64::MAX));
+    }
+
+    #[test]
+    fn test_relative_eq() {
+        assert!(relative_eq(1e10, 1e10 + 1.0, 0.0, 1e-9));
+        assert!(!relative_eq(1e10, 1.1e10, 0.0, 1e-9));
+        assert!(relative_eq(f64::INFINITY, f64::INFINITY, 0.0, 0.0));
+        assert!(!relative_eq(f64::INFINITY, f64::MAX, 0.0, 1.0));
+    }
+
+    #[test]
+    fn test_ulps_eq() {
+        let next = f64::from_bits(1.0f64.to_bits() + 2);
+        assert!(ulps_eq(1.0, next, 0.0, 2));
+        assert!(!ulps_eq(1.0, next, 0.0, 1));
+        assert!(ulps_eq(0.0, -0.0, 0.0, 0));
+        assert!(!ulps_eq(1e-300, -1e-300, 0.0, 4));
+    }
+}
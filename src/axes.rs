@@ -0,0 +1,332 @@
+use crate::Vector3;
+
+/// A signed coordinate axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Axis {
+    PosX,
+    NegX,
+    PosY,
+    NegY,
+    PosZ,
+    NegZ,
+}
+
+impl Axis {
+    /// Returns the component index of the axis: 0 for x, 1 for y and 2 for z.
+    #[inline]
+    #[must_use]
+    pub const fn index(self) -> usize {
+        match self {
+            Self::PosX | Self::NegX => 0,
+            Self::PosY | Self::NegY => 1,
+            Self::PosZ | Self::NegZ => 2,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn sign(self) -> f64 {
+        match self {
+            Self::PosX | Self::PosY | Self::PosZ => 1.0,
+            Self::NegX | Self::NegY | Self::NegZ => -1.0,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn to_vector(self) -> Vector3 {
+        match self {
+            Self::PosX => Vector3::X,
+            Self::NegX => Vector3::NEG_X,
+            Self::PosY => Vector3::Y,
+            Self::NegY => Vector3::NEG_Y,
+            Self::PosZ => Vector3::Z,
+            Self::NegZ => Vector3::NEG_Z,
+        }
+    }
+}
+
+/// Describes which local axes of a coordinate system point right, up and forward, where
+/// forward is the direction a camera with no rotation looks (into the screen).
+///
+/// Handedness follows from the axes: the convention is left-handed if `right × up` points
+/// forward, and right-handed if it points backward.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AxisConvention {
+    right: Axis,
+    up: Axis,
+    forward: Axis,
+}
+
+impl AxisConvention {
+    /// Blender: Z-up, right-handed, `+Y` forward.
+    pub const BLENDER: Self = Self {
+        right: Axis::PosX,
+        up: Axis::PosZ,
+        forward: Axis::PosY,
+    };
+    /// Unity: Y-up, left-handed, `+Z` forward.
+    pub const UNITY: Self = Self {
+        right: Axis::PosX,
+        up: Axis::PosY,
+        forward: Axis::PosZ,
+    };
+    /// OpenGL and glTF: Y-up, right-handed, `-Z` forward.
+    pub const OPENGL: Self = Self {
+        right: Axis::PosX,
+        up: Axis::PosY,
+        forward: Axis::NegZ,
+    };
+    /// Unreal Engine: Z-up, left-handed, `+X` forward.
+    pub const UNREAL: Self = Self {
+        right: Axis::PosY,
+        up: Axis::PosZ,
+        forward: Axis::PosX,
+    };
+
+    /// Returns `None` if two of the axes lie along the same coordinate.
+    #[must_use]
+    pub const fn new(right: Axis, up: Axis, forward: Axis) -> Option<Self> {
+        let (r, u, f) = (right.index(), up.index(), forward.index());
+        if r == u || u == f || r == f {
+            None
+        } else {
+            Some(Self { right, up, forward })
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn right(self) -> Axis {
+        self.right
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn up(self) -> Axis {
+        self.up
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn forward(self) -> Axis {
+        self.forward
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_right_handed(self) -> bool {
+        self.right
+            .to_vector()
+            .cross(self.up.to_vector())
+            .dot(self.forward.to_vector())
+            < 0.0
+    }
+
+    /// Returns the conversion from coordinates in `self` to coordinates in `target`.
+    #[must_use]
+    pub fn conversion_to(self, target: Self) -> AxisConversion {
+        let source_axes = [self.right, self.up, self.forward];
+        let target_axes = [target.right, target.up, target.forward];
+        let mut source = [0; 3];
+        let mut sign = [0.0; 3];
+        for (source_axis, target_axis) in source_axes.into_iter().zip(target_axes) {
+            source[target_axis.index()] = source_axis.index();
+            sign[target_axis.index()] = source_axis.sign() * target_axis.sign();
+        }
+        let handedness_sign = if self.is_right_handed() == target.is_right_handed() {
+            1.0
+        } else {
+            -1.0
+        };
+        AxisConversion {
+            source,
+            sign,
+            handedness_sign,
+        }
+    }
+}
+
+/// A precomputed signed axis permutation between two [`AxisConvention`]s.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AxisConversion {
+    source: [usize; 3],
+    sign: [f64; 3],
+    handedness_sign: f64,
+}
+
+impl AxisConversion {
+    /// Returns `true` if the conversion flips handedness, i.e. it is a reflection.
+    #[inline]
+    #[must_use]
+    pub fn flips_handedness(&self) -> bool {
+        self.handedness_sign < 0.0
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn position(&self, v: Vector3) -> Vector3 {
+        let [a, b, c] = self.source;
+        let [sa, sb, sc] = self.sign;
+        Vector3::new(sa * v[a], sb * v[b], sc * v[c])
+    }
+
+    /// Converts a direction or normal. Identical to [`position`](Self::position), since the
+    /// conversion has no translation.
+    #[inline]
+    #[must_use]
+    pub fn direction(&self, v: Vector3) -> Vector3 {
+        self.position(v)
+    }
+
+    /// Converts the result of a cross product (an axial vector such as an angular velocity
+    /// or a winding-order normal), negating it when the handedness changes so that
+    /// `cross_product(a.cross(b)) == position(a).cross(position(b))`.
+    #[inline]
+    #[must_use]
+    pub fn cross_product(&self, v: Vector3) -> Vector3 {
+        self.position(v) * self.handedness_sign
+    }
+
+    pub fn positions(&self, vectors: &mut [Vector3]) {
+        for v in vectors {
+            *v = self.position(*v);
+        }
+    }
+
+    pub fn directions(&self, vectors: &mut [Vector3]) {
+        self.positions(vectors);
+    }
+
+    pub fn cross_products(&self, vectors: &mut [Vector3]) {
+        for v in vectors {
+            *v = self.cross_product(*v);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AXES: [Axis; 6] = [
+        Axis::PosX,
+        Axis::NegX,
+        Axis::PosY,
+        Axis::NegY,
+        Axis::PosZ,
+        Axis::NegZ,
+    ];
+
+    fn all_conventions() -> Vec<AxisConvention> {
+        let mut conventions = Vec::new();
+        for right in AXES {
+            for up in AXES {
+                for forward in AXES {
+                    conventions.extend(AxisConvention::new(right, up, forward));
+                }
+            }
+        }
+        conventions
+    }
+
+    fn canonical(convention: AxisConvention, v: Vector3) -> [f64; 3] {
+        [convention.right, convention.up, convention.forward].map(|axis| axis.to_vector().dot(v))
+    }
+
+    #[test]
+    fn test_new_rejects_repeated_axes() {
+        assert!(AxisConvention::new(Axis::PosX, Axis::NegX, Axis::PosZ).is_none());
+        assert!(AxisConvention::new(Axis::PosX, Axis::PosY, Axis::PosY).is_none());
+        assert_eq!(all_conventions().len(), 48);
+    }
+
+    #[test]
+    fn test_handedness() {
+        assert!(AxisConvention::BLENDER.is_right_handed());
+        assert!(AxisConvention::OPENGL.is_right_handed());
+        assert!(!AxisConvention::UNITY.is_right_handed());
+        assert!(!AxisConvention::UNREAL.is_right_handed());
+        let right_handed = all_conventions()
+            .into_iter()
+            .filter(|c| c.is_right_handed())
+            .count();
+        assert_eq!(right_handed, 24);
+    }
+
+    #[test]
+    fn test_blender_to_unity() {
+        let conversion = AxisConvention::BLENDER.conversion_to(AxisConvention::UNITY);
+        assert!(conversion.flips_handedness());
+        assert_eq!(
+            conversion.position(Vector3::new(1.0, 2.0, 3.0)),
+            Vector3::new(1.0, 3.0, 2.0)
+        );
+        assert_eq!(conversion.cross_product(Vector3::Z), Vector3::NEG_Y);
+    }
+
+    #[test]
+    fn test_opengl_to_unreal() {
+        let conversion = AxisConvention::OPENGL.conversion_to(AxisConvention::UNREAL);
+        assert_eq!(conversion.position(Vector3::NEG_Z), Vector3::X);
+        assert_eq!(conversion.position(Vector3::X), Vector3::Y);
+        assert_eq!(conversion.position(Vector3::Y), Vector3::Z);
+    }
+
+    #[test]
+    fn test_all_conversions_preserve_meaning() {
+        let a = Vector3::new(1.0, -2.0, 3.0);
+        let b = Vector3::new(-4.0, 0.5, 6.0);
+        let conventions = all_conventions();
+        for &source in &conventions {
+            for &target in &conventions {
+                let conversion = source.conversion_to(target);
+                let back = target.conversion_to(source);
+                let converted = conversion.position(a);
+
+                assert_eq!(canonical(target, converted), canonical(source, a));
+                assert_eq!(back.position(converted), a);
+                assert_eq!(conversion.direction(b), conversion.position(b));
+                assert_eq!(
+                    conversion.cross_product(a.cross(b)),
+                    converted.cross(conversion.position(b))
+                );
+                assert_eq!(
+                    conversion.flips_handedness(),
+                    source.is_right_handed() != target.is_right_handed()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_identity_conversion() {
+        for convention in all_conventions() {
+            let conversion = convention.conversion_to(convention);
+            assert!(!conversion.flips_handedness());
+            assert_eq!(
+                conversion.position(Vector3::new(1.0, 2.0, 3.0)),
+                Vector3::new(1.0, 2.0, 3.0)
+            );
+        }
+    }
+
+    #[test]
+    fn test_batch_conversion() {
+        let conversion = AxisConvention::BLENDER.conversion_to(AxisConvention::OPENGL);
+        let original = [Vector3::new(1.0, 2.0, 3.0), Vector3::new(-1.0, 0.0, 5.0)];
+
+        let mut positions = original;
+        conversion.positions(&mut positions);
+        let mut directions = original;
+        conversion.directions(&mut directions);
+        let mut normals = original;
+        conversion.cross_products(&mut normals);
+
+        for i in 0..original.len() {
+            assert_eq!(positions[i], conversion.position(original[i]));
+            assert_eq!(directions[i], conversion.direction(original[i]));
+            assert_eq!(normals[i], conversion.cross_product(original[i]));
+        }
+    }
+}
@@ -0,0 +1,254 @@
+//! Lane types backing `Vector2`, `Vector3` and the packet types.
+//!
+//! By default these are the nightly `core::simd` types. With the `scalar-math` feature they are
+//! plain arrays with the same interface, which builds on stable Rust. Both backends perform
+//! the same IEEE operations in the same order, so results are bit-for-bit identical.
+
+#[cfg(not(feature = "scalar-math"))]
+pub(crate) use core::simd::{Select, cmp::SimdPartialEq, num::SimdFloat, simd_swizzle};
+#[cfg(not(feature = "scalar-math"))]
+pub use core::simd::{f64x2, f64x4};
+
+#[cfg(feature = "scalar-math")]
+pub(crate) use scalar::{Lanes, Select, SimdFloat, SimdPartialEq};
+#[cfg(feature = "scalar-math")]
+pub use scalar::{f64x2, f64x4};
+
+/// Lane-wise square root.
+#[inline]
+pub(crate) fn sqrt_lanes(lanes: f64x4) -> f64x4 {
+    #[cfg(all(feature = "std", not(feature = "scalar-math")))]
+    {
+        std::simd::StdFloat::sqrt(lanes)
+    }
+    #[cfg(not(all(feature = "std", not(feature = "scalar-math"))))]
+    {
+        f64x4::from_array(lanes.to_array().map(crate::math::sqrt))
+    }
+}
+
+/// Scalar stand-in for `core::simd::simd_swizzle!`. With two inputs, indices past the end of
+/// the first vector select from the second.
+#[cfg(feature = "scalar-math")]
+macro_rules! scalar_swizzle {
+    ($vector:expr, [$($index:expr),+ $(,)?]) => {{
+        let lanes = $vector;
+        $crate::backend::Lanes::from_array([$(lanes[$index]),+])
+    }};
+    ($first:expr, $second:expr, [$($index:expr),+ $(,)?]) => {{
+        let (first, second) = ($first, $second);
+        let len = first.as_array().len();
+        let lane = |index: usize| if index < len { first[index] } else { second[index - len] };
+        $crate::backend::Lanes::from_array([$(lane($index)),+])
+    }};
+}
+
+#[cfg(feature = "scalar-math")]
+pub(crate) use scalar_swizzle as simd_swizzle;
+
+#[cfg(feature = "scalar-math")]
+mod scalar {
+    use core::ops::{
+        Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+    };
+
+    /// Array-backed stand-in for `core::simd::Simd<f64, N>`, supporting construction,
+    /// lane access, and lane-wise arithmetic. Laid out exactly as `[f64; N]`.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    #[repr(transparent)]
+    pub struct Lanes<const N: usize>([f64; N]);
+
+    #[allow(non_camel_case_types)]
+    pub type f64x2 = Lanes<2>;
+    #[allow(non_camel_case_types)]
+    pub type f64x4 = Lanes<4>;
+
+    impl<const N: usize> Lanes<N> {
+        #[inline]
+        pub const fn from_array(array: [f64; N]) -> Self {
+            Self(array)
+        }
+
+        #[inline]
+        pub const fn as_array(&self) -> &[f64; N] {
+            &self.0
+        }
+
+        #[inline]
+        pub const fn to_array(self) -> [f64; N] {
+            self.0
+        }
+
+        #[inline]
+        pub fn as_mut_array(&mut self) -> &mut [f64; N] {
+            &mut self.0
+        }
+
+        #[inline]
+        pub fn splat(value: f64) -> Self {
+            Self([value; N])
+        }
+
+        /// # Panics
+        ///
+        /// Panics if `slice` has fewer than `N` elements.
+        #[inline]
+        pub fn from_slice(slice: &[f64]) -> Self {
+            assert!(
+                slice.len() >= N,
+                "slice length must be at least the number of lanes"
+            );
+            Self(core::array::from_fn(|i| slice[i]))
+        }
+
+        #[inline]
+        fn zip_with(self, rhs: Self, f: impl Fn(f64, f64) -> f64) -> Self {
+            Self(core::array::from_fn(|i| f(self.0[i], rhs.0[i])))
+        }
+    }
+
+    /// Array-backed stand-in for `core::simd::Mask`, as returned by lane-wise comparisons.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Mask<const N: usize>([bool; N]);
+
+    pub(crate) trait Select<T> {
+        /// Takes each lane from `true_values` where the mask is set, else from `false_values`.
+        fn select(self, true_values: T, false_values: T) -> T;
+    }
+
+    impl<const N: usize> Select<Lanes<N>> for Mask<N> {
+        #[inline]
+        fn select(self, true_values: Lanes<N>, false_values: Lanes<N>) -> Lanes<N> {
+            Lanes(core::array::from_fn(|i| {
+                if self.0[i] {
+                    true_values.0[i]
+                } else {
+                    false_values.0[i]
+                }
+            }))
+        }
+    }
+
+    pub(crate) trait SimdPartialEq {
+        type Mask;
+
+        fn simd_eq(self, rhs: Self) -> Self::Mask;
+    }
+
+    impl<const N: usize> SimdPartialEq for Lanes<N> {
+        type Mask = Mask<N>;
+
+        #[inline]
+        fn simd_eq(self, rhs: Self) -> Mask<N> {
+            Mask(core::array::from_fn(|i| self.0[i] == rhs.0[i]))
+        }
+    }
+
+    pub(crate) trait SimdFloat {
+        fn reduce_sum(self) -> f64;
+        fn simd_min(self, rhs: Self) -> Self;
+        fn simd_max(self, rhs: Self) -> Self;
+    }
+
+    impl<const N: usize> SimdFloat for Lanes<N> {
+        /// Sums the lanes in order starting from `-0.0`, matching `core::simd`.
+        #[inline]
+        fn reduce_sum(self) -> f64 {
+            self.0.into_iter().fold(-0.0, |sum, lane| sum + lane)
+        }
+
+        #[inline]
+        fn simd_min(self, rhs: Self) -> Self {
+            self.zip_with(rhs, f64::min)
+        }
+
+        #[inline]
+        fn simd_max(self, rhs: Self) -> Self {
+            self.zip_with(rhs, f64::max)
+        }
+    }
+
+    impl<const N: usize> Index<usize> for Lanes<N> {
+        type Output = f64;
+
+        #[inline]
+        fn index(&self, index: usize) -> &f64 {
+            &self.0[index]
+        }
+    }
+
+    impl<const N: usize> IndexMut<usize> for Lanes<N> {
+        #[inline]
+        fn index_mut(&mut self, index: usize) -> &mut f64 {
+            &mut self.0[index]
+        }
+    }
+
+    impl<const N: usize> Neg for Lanes<N> {
+        type Output = Self;
+
+        #[inline]
+        fn neg(self) -> Self {
+            Self(self.0.map(|lane| -lane))
+        }
+    }
+
+    macro_rules! impl_lane_op {
+        ($op:ident, $method:ident, $assign_op:ident, $assign_method:ident, $symbol:tt) => {
+            impl<const N: usize> $op for Lanes<N> {
+                type Output = Self;
+
+                #[inline]
+                fn $method(self, rhs: Self) -> Self {
+                    self.zip_with(rhs, |a, b| a $symbol b)
+                }
+            }
+
+            impl<const N: usize> $assign_op for Lanes<N> {
+                #[inline]
+                fn $assign_method(&mut self, rhs: Self) {
+                    *self = self.$method(rhs);
+                }
+            }
+        };
+    }
+
+    impl_lane_op!(Add, add, AddAssign, add_assign, +);
+    impl_lane_op!(Sub, sub, SubAssign, sub_assign, -);
+    impl_lane_op!(Mul, mul, MulAssign, mul_assign, *);
+    impl_lane_op!(Div, div, DivAssign, div_assign, /);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reduce_sum_is_ordered() {
+        // Summed pairwise this would be 0.0; summed in lane order it is 1.0.
+        let lanes = f64x4::from_array([1e16, 1.0, -1e16, 1.0]);
+        assert_eq!(lanes.reduce_sum(), 1.0);
+        let zeros = f64x4::from_array([-0.0; 4]);
+        assert!(zeros.reduce_sum().is_sign_negative());
+    }
+
+    #[test]
+    fn test_select() {
+        let lanes = f64x4::from_array([0.0, -0.0, f64::NAN, 2.0]);
+        let zero = f64x4::splat(0.0);
+        let selected = lanes.simd_eq(zero).select(f64x4::splat(1.0), lanes);
+        assert_eq!(selected.as_array()[..2], [1.0, 1.0]);
+        assert!(selected[2].is_nan());
+        assert_eq!(selected[3], 2.0);
+    }
+
+    #[test]
+    fn test_swizzle() {
+        let lanes = f64x2::from_array([1.0, 2.0]);
+        let swapped: f64x2 = simd_swizzle!(lanes, [1, 0]);
+        assert_eq!(*swapped.as_array(), [2.0, 1.0]);
+        let other = f64x2::from_array([3.0, 4.0]);
+        let mixed: f64x4 = simd_swizzle!(lanes, other, [3, 0, 2, 1]);
+        assert_eq!(*mixed.as_array(), [4.0, 1.0, 3.0, 2.0]);
+    }
+}
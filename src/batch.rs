@@ -0,0 +1,325 @@
+//! Operations over whole slices of [`Vector2`] or [`Vector3`].
+//!
+//! For `Vector3` slices the element-wise functions run the [`kernels`], which work on four
+//! vectors per register with the best instruction set the CPU supports. A `Vector2` already
+//! fills a 128-bit register, so its slices go through plain loops. The reductions keep four
+//! independent accumulators, so consecutive adds don't wait on each other. Element-wise
+//! results are identical to applying the vector method to each element; [`sum`] adds in a
+//! different order than [`Iterator::sum`], so it can differ in the last bits.
+
+use crate::{Vector2, Vector3, kernels};
+use core::ops::{Add, AddAssign, MulAssign};
+
+mod sealed {
+    /// The element-wise slice operations, implemented per vector type.
+    pub trait Sealed: Sized {
+        fn add_assign_all(vectors: &mut [Self], offset: Self);
+        fn scale_all(vectors: &mut [Self], factor: f64);
+        fn normalize_all(vectors: &mut [Self]);
+        fn dots(a: &[Self], b: &[Self], out: &mut [f64]);
+        fn distances_to(vectors: &[Self], point: Self, out: &mut [f64]);
+    }
+}
+
+impl sealed::Sealed for Vector2 {
+    fn add_assign_all(vectors: &mut [Self], offset: Self) {
+        vectors.iter_mut().for_each(|v| *v += offset);
+    }
+
+    fn scale_all(vectors: &mut [Self], factor: f64) {
+        vectors.iter_mut().for_each(|v| *v *= factor);
+    }
+
+    fn normalize_all(vectors: &mut [Self]) {
+        vectors.iter_mut().for_each(|v| *v = v.normalize());
+    }
+
+    fn dots(a: &[Self], b: &[Self], out: &mut [f64]) {
+        for ((o, a), b) in out.iter_mut().zip(a).zip(b) {
+            *o = a.dot(*b);
+        }
+    }
+
+    fn distances_to(vectors: &[Self], point: Self, out: &mut [f64]) {
+        for (o, v) in out.iter_mut().zip(vectors) {
+            *o = v.distance(point);
+        }
+    }
+}
+
+impl sealed::Sealed for Vector3 {
+    fn add_assign_all(vectors: &mut [Self], offset: Self) {
+        kernels::translate(vectors, offset);
+    }
+
+    fn scale_all(vectors: &mut [Self], factor: f64) {
+        kernels::scale(vectors, factor);
+    }
+
+    fn normalize_all(vectors: &mut [Self]) {
+        kernels::normalize(vectors);
+    }
+
+    fn dots(a: &[Self], b: &[Self], out: &mut [f64]) {
+        kernels::dot(a, b, out);
+    }
+
+    fn distances_to(vectors: &[Self], point: Self, out: &mut [f64]) {
+        kernels::distance_to(vectors, point, out);
+    }
+}
+
+/// A vector type the batch functions accept: [`Vector2`] or [`Vector3`].
+///
+/// This trait is sealed and only forwards to the inherent methods of the same name.
+pub trait BatchVector:
+    sealed::Sealed + Copy + Add<Output = Self> + AddAssign + MulAssign<f64>
+{
+    const ZERO: Self;
+
+    #[must_use]
+    fn dot(self, rhs: Self) -> f64;
+
+    #[must_use]
+    fn distance(self, rhs: Self) -> f64;
+
+    #[must_use]
+    fn distance_squared(self, rhs: Self) -> f64;
+
+    #[must_use]
+    fn normalize(self) -> Self;
+
+    #[must_use]
+    fn min(self, rhs: Self) -> Self;
+
+    #[must_use]
+    fn max(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_batch_vector {
+    ($vector:ident) => {
+        impl BatchVector for $vector {
+            const ZERO: Self = $vector::ZERO;
+
+            #[inline(always)]
+            fn dot(self, rhs: Self) -> f64 {
+                $vector::dot(self, rhs)
+            }
+
+            #[inline(always)]
+            fn distance(self, rhs: Self) -> f64 {
+                $vector::distance(self, rhs)
+            }
+
+            #[inline(always)]
+            fn distance_squared(self, rhs: Self) -> f64 {
+                $vector::distance_squared(self, rhs)
+            }
+
+            #[inline(always)]
+            fn normalize(self) -> Self {
+                $vector::normalize(self)
+            }
+
+            #[inline(always)]
+            fn min(self, rhs: Self) -> Self {
+                $vector::min(self, rhs)
+            }
+
+            #[inline(always)]
+            fn max(self, rhs: Self) -> Self {
+                $vector::max(self, rhs)
+            }
+        }
+    };
+}
+
+impl_batch_vector!(Vector2);
+impl_batch_vector!(Vector3);
+
+/// Adds `offset` to every vector.
+pub fn add_assign_all<V: BatchVector>(vectors: &mut [V], offset: V) {
+    V::add_assign_all(vectors, offset);
+}
+
+/// Multiplies every vector by `factor`.
+pub fn scale_all<V: BatchVector>(vectors: &mut [V], factor: f64) {
+    V::scale_all(vectors, factor);
+}
+
+/// Normalizes every vector. Zero vectors stay zero, as with `normalize`.
+pub fn normalize_all<V: BatchVector>(vectors: &mut [V]) {
+    V::normalize_all(vectors);
+}
+
+/// Writes `a[i].dot(b[i])` to `out[i]`.
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+pub fn dots<V: BatchVector>(a: &[V], b: &[V], out: &mut [f64]) {
+    assert!(
+        a.len() == b.len() && a.len() == out.len(),
+        "slice lengths differ"
+    );
+    V::dots(a, b, out);
+}
+
+/// Writes the distance from each vector to `point` to `out`.
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+pub fn distances_to<V: BatchVector>(vectors: &[V], point: V, out: &mut [f64]) {
+    assert!(vectors.len() == out.len(), "slice lengths differ");
+    V::distances_to(vectors, point, out);
+}
+
+/// Returns the sum of the vectors, or zero for an empty slice.
+///
+/// Element `i` goes into accumulator `i % 4` and the accumulators are added at the end, so the
+/// result is deterministic but may differ in rounding from summing in order.
+#[must_use]
+pub fn sum<V: BatchVector>(vectors: &[V]) -> V {
+    let mut acc = [V::ZERO; 4];
+    let mut chunks = vectors.chunks_exact(4);
+    for chunk in &mut chunks {
+        acc[0] += chunk[0];
+        acc[1] += chunk[1];
+        acc[2] += chunk[2];
+        acc[3] += chunk[3];
+    }
+    for (a, v) in acc.iter_mut().zip(chunks.remainder()) {
+        *a += *v;
+    }
+    (acc[0] + acc[1]) + (acc[2] + acc[3])
+}
+
+/// Returns the component-wise minimum and maximum, or `None` for an empty slice. NaN
+/// components are ignored unless every value of that component is NaN.
+#[must_use]
+pub fn min_max_bounds<V: BatchVector>(vectors: &[V]) -> Option<(V, V)> {
+    let first = *vectors.first()?;
+    let mut min = [first; 4];
+    let mut max = [first; 4];
+    let mut chunks = vectors.chunks_exact(4);
+    for chunk in &mut chunks {
+        for i in 0..4 {
+            min[i] = min[i].min(chunk[i]);
+            max[i] = max[i].max(chunk[i]);
+        }
+    }
+    for (i, v) in chunks.remainder().iter().enumerate() {
+        min[i] = min[i].min(*v);
+        max[i] = max[i].max(*v);
+    }
+    Some((
+        min[0].min(min[1]).min(min[2].min(min[3])),
+        max[0].max(max[1]).max(max[2].max(max[3])),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::sample_vectors;
+
+    fn sample2(len: usize) -> Vec<Vector2> {
+        sample_vectors(len, 0.0)
+            .into_iter()
+            .map(|v| Vector2::new(v.x(), v.z()))
+            .collect()
+    }
+
+    #[test]
+    fn test_element_wise_matches_methods() {
+        // Lengths around the kernels' four-vector chunks, so the remainder loops run too.
+        for len in [0, 3, 4, 9] {
+            let a = sample_vectors(len, 0.0);
+            let b = sample_vectors(len, 5.0);
+            let offset = Vector3::new(1.0, -2.0, 0.5);
+
+            let mut moved = a.clone();
+            add_assign_all(&mut moved, offset);
+            scale_all(&mut moved, -1.5);
+            let mut normalized = a.clone();
+            normalize_all(&mut normalized);
+            let mut d = vec![0.0; len];
+            dots(&a, &b, &mut d);
+            let mut dist = vec![0.0; len];
+            distances_to(&a, offset, &mut dist);
+
+            for i in 0..len {
+                assert_eq!(moved[i], (a[i] + offset) * -1.5);
+                assert_eq!(normalized[i], a[i].normalize());
+                assert_eq!(d[i].to_bits(), a[i].dot(b[i]).to_bits());
+                assert_eq!(dist[i], a[i].distance(offset));
+            }
+        }
+    }
+
+    #[test]
+    fn test_vector2() {
+        let vectors = sample2(7);
+        let mut scaled = vectors.clone();
+        scale_all(&mut scaled, 2.0);
+        for (s, v) in scaled.iter().zip(&vectors) {
+            assert_eq!(*s, *v * 2.0);
+        }
+        let mut d = vec![0.0; 7];
+        dots(&vectors, &scaled, &mut d);
+        assert_eq!(d[6], vectors[6].dot(scaled[6]));
+
+        let (min, max) = min_max_bounds(&vectors).unwrap();
+        for v in &vectors {
+            assert_eq!(v.min(min), min);
+            assert_eq!(v.max(max), max);
+        }
+        let total = sum(&vectors);
+        let expected: Vector2 = vectors.iter().sum();
+        assert!(total.abs_diff_eq(expected, 1e-12));
+    }
+
+    #[test]
+    fn test_sum() {
+        assert_eq!(sum::<Vector3>(&[]), Vector3::ZERO);
+        let vectors = sample_vectors(11, 0.0);
+        let expected: Vector3 = vectors.iter().sum();
+        assert!(sum(&vectors).abs_diff_eq(expected, 1e-12));
+        // Small integers sum exactly in any order.
+        let ints: Vec<Vector3> = (0..10).map(|i| Vector3::new(i as f64, 1.0, -2.0)).collect();
+        assert_eq!(sum(&ints), Vector3::new(45.0, 10.0, -20.0));
+    }
+
+    #[test]
+    fn test_min_max_bounds() {
+        assert_eq!(min_max_bounds::<Vector3>(&[]), None);
+        let mut vectors = sample_vectors(10, 0.0);
+        vectors[7] = Vector3::new(f64::NAN, 100.0, -100.0);
+        let (min, max) = min_max_bounds(&vectors).unwrap();
+        let expected_min = vectors.iter().fold(Vector3::INFINITY, |m, v| m.min(*v));
+        let expected_max = vectors.iter().fold(Vector3::NEG_INFINITY, |m, v| m.max(*v));
+        assert_eq!(min, expected_min);
+        assert_eq!(max, expected_max);
+        assert_eq!(max.y(), 100.0);
+        assert!(!min.x().is_nan());
+        assert_eq!(
+            min_max_bounds(&[Vector3::ONE]),
+            Some((Vector3::ONE, Vector3::ONE))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "slice lengths differ")]
+    fn test_length_mismatch() {
+        let a = sample_vectors(5, 0.0);
+        distances_to(&a, Vector3::ZERO, &mut [0.0; 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "slice lengths differ")]
+    fn test_dots_length_mismatch() {
+        let a = sample2(5);
+        dots(&a, &a[1..], &mut [0.0; 5]);
+    }
+}
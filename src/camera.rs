@@ -0,0 +1,410 @@
+use crate::math;
+use crate::{FastVecError, Matrix4, Vector2, Vector3};
+
+// Conventions: view space is right-handed with the camera looking down -Z and +Y up, NDC
+// depth is in [0, 1] (reversed-Z swaps the ends), and screen coordinates are pixels with
+// the origin at the top-left corner and +Y pointing down.
+
+impl Matrix4 {
+    /// Builds a right-handed view matrix for a camera at `eye` looking at `target`.
+    ///
+    /// Returns [`FastVecError::ZeroLength`] if `eye == target` and
+    /// [`FastVecError::LinearlyDependent`] if `up` is parallel to the view direction.
+    pub fn look_at(eye: Vector3, target: Vector3, up: Vector3) -> Result<Self, FastVecError> {
+        let forward = (target - eye).try_normalize()?;
+        let right = forward
+            .cross(up)
+            .try_normalize()
+            .map_err(|_| FastVecError::LinearlyDependent)?;
+        let up = right.cross(forward);
+        Ok(Self::from_rows([
+            [right.x(), right.y(), right.z(), -right.dot(eye)],
+            [up.x(), up.y(), up.z(), -up.dot(eye)],
+            [-forward.x(), -forward.y(), -forward.z(), forward.dot(eye)],
+            [0.0, 0.0, 0.0, 1.0],
+        ]))
+    }
+}
+
+/// A projection matrix together with the NDC depth it assigns to the near plane.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Projection {
+    matrix: Matrix4,
+    reversed_z: bool,
+}
+
+impl Projection {
+    /// Perspective projection with a vertical field of view of `fov_y` radians, mapping the
+    /// near plane to depth 0 and the far plane to depth 1.
+    #[must_use]
+    pub fn perspective(fov_y: f64, aspect: f64, near: f64, far: f64) -> Self {
+        let range = near - far;
+        Self::perspective_with_depth(fov_y, aspect, far / range, near * far / range, false)
+    }
+
+    /// Like [`perspective`](Self::perspective), but mapping the near plane to depth 1 and the
+    /// far plane to depth 0 for better floating-point depth precision.
+    #[must_use]
+    pub fn perspective_reversed_z(fov_y: f64, aspect: f64, near: f64, far: f64) -> Self {
+        let range = far - near;
+        Self::perspective_with_depth(fov_y, aspect, near / range, near * far / range, true)
+    }
+
+    /// Perspective projection with the far plane at infinity, which maps to depth 1.
+    #[must_use]
+    pub fn perspective_infinite(fov_y: f64, aspect: f64, near: f64) -> Self {
+        Self::perspective_with_depth(fov_y, aspect, -1.0, -near, false)
+    }
+
+    /// Perspective projection with the far plane at infinity, which maps to depth 0.
+    #[must_use]
+    pub fn perspective_infinite_reversed_z(fov_y: f64, aspect: f64, near: f64) -> Self {
+        Self::perspective_with_depth(fov_y, aspect, 0.0, near, true)
+    }
+
+    /// Orthographic projection of the view-space box, mapping the near plane to depth 0 and
+    /// the far plane to depth 1.
+    #[must_use]
+    pub fn orthographic(left: f64, right: f64, bottom: f64, top: f64, near: f64, far: f64) -> Self {
+        let range = far - near;
+        Self::orthographic_with_depth(left, right, bottom, top, -1.0 / range, -near / range, false)
+    }
+
+    /// Like [`orthographic`](Self::orthographic), but mapping the near plane to depth 1 and
+    /// the far plane to depth 0.
+    #[must_use]
+    pub fn orthographic_reversed_z(
+        left: f64,
+        right: f64,
+        bottom: f64,
+        top: f64,
+        near: f64,
+        far: f64,
+    ) -> Self {
+        let range = far - near;
+        Self::orthographic_with_depth(left, right, bottom, top, 1.0 / range, far / range, true)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn matrix(&self) -> Matrix4 {
+        self.matrix
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_reversed_z(&self) -> bool {
+        self.reversed_z
+    }
+
+    /// Returns the NDC depth of the near plane: 0, or 1 with reversed-Z.
+    #[inline]
+    #[must_use]
+    pub fn near_depth(&self) -> f64 {
+        if self.reversed_z { 1.0 } else { 0.0 }
+    }
+
+    fn perspective_with_depth(
+        fov_y: f64,
+        aspect: f64,
+        depth_scale: f64,
+        depth_offset: f64,
+        reversed_z: bool,
+    ) -> Self {
+        let focal = 1.0 / math::tan(fov_y / 2.0);
+        let matrix = Matrix4::from_rows([
+            [focal / aspect, 0.0, 0.0, 0.0],
+            [0.0, focal, 0.0, 0.0],
+            [0.0, 0.0, depth_scale, depth_offset],
+            [0.0, 0.0, -1.0, 0.0],
+        ]);
+        Self { matrix, reversed_z }
+    }
+
+    fn orthographic_with_depth(
+        left: f64,
+        right: f64,
+        bottom: f64,
+        top: f64,
+        depth_scale: f64,
+        depth_offset: f64,
+        reversed_z: bool,
+    ) -> Self {
+        let width = right - left;
+        let height = top - bottom;
+        let matrix = Matrix4::from_rows([
+            [2.0 / width, 0.0, 0.0, -(right + left) / width],
+            [0.0, 2.0 / height, 0.0, -(top + bottom) / height],
+            [0.0, 0.0, depth_scale, depth_offset],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+        Self { matrix, reversed_z }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ray {
+    pub origin: Vector3,
+    /// Unit direction of the ray.
+    pub direction: Vector3,
+}
+
+impl Ray {
+    #[inline]
+    #[must_use]
+    pub fn at(&self, t: f64) -> Vector3 {
+        self.origin + self.direction * t
+    }
+}
+
+/// A view and projection bound to a viewport, for mapping between world, NDC and screen
+/// coordinates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Camera {
+    view_projection: Matrix4,
+    inverse_view_projection: Matrix4,
+    projection: Projection,
+    viewport: Vector2,
+}
+
+impl Camera {
+    /// Returns [`FastVecError::LinearlyDependent`] if the combined view-projection matrix is
+    /// not invertible.
+    pub fn new(
+        view: Matrix4,
+        projection: Projection,
+        viewport: Vector2,
+    ) -> Result<Self, FastVecError> {
+        let view_projection = projection.matrix() * view;
+        let inverse_view_projection = view_projection
+            .inverse()
+            .ok_or(FastVecError::LinearlyDependent)?;
+        Ok(Self {
+            view_projection,
+            inverse_view_projection,
+            projection,
+            viewport,
+        })
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn view_projection(&self) -> Matrix4 {
+        self.view_projection
+    }
+
+    /// Projects a world-space point to normalized device coordinates.
+    #[inline]
+    #[must_use]
+    pub fn project(&self, world: Vector3) -> Vector3 {
+        self.view_projection.transform_point(world)
+    }
+
+    /// Converts normalized device coordinates back to a world-space point.
+    #[inline]
+    #[must_use]
+    pub fn unproject_ndc(&self, ndc: Vector3) -> Vector3 {
+        self.inverse_view_projection.transform_point(ndc)
+    }
+
+    /// Converts NDC `x`/`y` to screen pixels.
+    #[inline]
+    #[must_use]
+    pub fn ndc_to_screen(&self, ndc: Vector3) -> Vector2 {
+        Vector2::new(
+            (ndc.x() + 1.0) * 0.5 * self.viewport.x(),
+            (1.0 - ndc.y()) * 0.5 * self.viewport.y(),
+        )
+    }
+
+    /// Returns the world-space point under the `screen` pixel at NDC `depth`.
+    #[inline]
+    #[must_use]
+    pub fn unproject(&self, screen: Vector2, depth: f64) -> Vector3 {
+        let ndc_x = 2.0 * screen.x() / self.viewport.x() - 1.0;
+        let ndc_y = 1.0 - 2.0 * screen.y() / self.viewport.y();
+        self.unproject_ndc(Vector3::new(ndc_x, ndc_y, depth))
+    }
+
+    /// Returns the ray through the `screen` pixel, starting on the near plane.
+    #[must_use]
+    pub fn screen_ray(&self, screen: Vector2) -> Ray {
+        // Depth 0.5 is finite for every projection, including infinite-far ones.
+        let origin = self.unproject(screen, self.projection.near_depth());
+        let through = self.unproject(screen, 0.5);
+        Ray {
+            origin,
+            direction: (through - origin).normalize(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_vec_approx_eq;
+    use core::f64::consts::FRAC_PI_2;
+
+    fn projections() -> [Projection; 6] {
+        [
+            Projection::perspective(FRAC_PI_2, 2.0, 0.5, 100.0),
+            Projection::perspective_reversed_z(FRAC_PI_2, 2.0, 0.5, 100.0),
+            Projection::perspective_infinite(FRAC_PI_2, 2.0, 0.5),
+            Projection::perspective_infinite_reversed_z(FRAC_PI_2, 2.0, 0.5),
+            Projection::orthographic(-4.0, 4.0, -2.0, 2.0, 0.5, 100.0),
+            Projection::orthographic_reversed_z(-4.0, 4.0, -2.0, 2.0, 0.5, 100.0),
+        ]
+    }
+
+    fn depth(projection: &Projection, view_z: f64) -> f64 {
+        projection
+            .matrix()
+            .transform_point(Vector3::new(0.0, 0.0, view_z))
+            .z()
+    }
+
+    #[test]
+    fn test_look_at() {
+        let view =
+            Matrix4::look_at(Vector3::new(0.0, 0.0, 5.0), Vector3::ZERO, Vector3::Y).unwrap();
+        assert_vec_approx_eq!(
+            view.transform_point(Vector3::ZERO),
+            Vector3::new(0.0, 0.0, -5.0)
+        );
+        assert_vec_approx_eq!(view.transform_vector(Vector3::X), Vector3::X);
+
+        let view = Matrix4::look_at(
+            Vector3::new(1.0, 2.0, 3.0),
+            Vector3::new(4.0, 2.0, 3.0),
+            Vector3::Z,
+        )
+        .unwrap();
+        assert_vec_approx_eq!(
+            view.transform_point(Vector3::new(5.0, 2.0, 3.0)),
+            Vector3::new(0.0, 0.0, -4.0)
+        );
+        assert_vec_approx_eq!(view.transform_vector(Vector3::Z), Vector3::Y);
+    }
+
+    #[test]
+    fn test_look_at_degenerate() {
+        assert_eq!(
+            Matrix4::look_at(Vector3::ONE, Vector3::ONE, Vector3::Y),
+            Err(FastVecError::ZeroLength)
+        );
+        assert_eq!(
+            Matrix4::look_at(Vector3::ZERO, Vector3::Y, Vector3::Y),
+            Err(FastVecError::LinearlyDependent)
+        );
+    }
+
+    #[test]
+    fn test_depth_ranges() {
+        for projection in projections() {
+            let near = projection.near_depth();
+            assert!(
+                (depth(&projection, -0.5) - near).abs() < 1e-12,
+                "{projection:?}"
+            );
+        }
+        let finite = [
+            (Projection::perspective(FRAC_PI_2, 2.0, 0.5, 100.0), 1.0),
+            (
+                Projection::perspective_reversed_z(FRAC_PI_2, 2.0, 0.5, 100.0),
+                0.0,
+            ),
+            (
+                Projection::orthographic(-4.0, 4.0, -2.0, 2.0, 0.5, 100.0),
+                1.0,
+            ),
+            (
+                Projection::orthographic_reversed_z(-4.0, 4.0, -2.0, 2.0, 0.5, 100.0),
+                0.0,
+            ),
+        ];
+        for (projection, far) in finite {
+            assert!(
+                (depth(&projection, -100.0) - far).abs() < 1e-12,
+                "{projection:?}"
+            );
+        }
+        let infinite = [
+            (Projection::perspective_infinite(FRAC_PI_2, 2.0, 0.5), 1.0),
+            (
+                Projection::perspective_infinite_reversed_z(FRAC_PI_2, 2.0, 0.5),
+                0.0,
+            ),
+        ];
+        for (projection, far) in infinite {
+            assert!(
+                (depth(&projection, -1e12) - far).abs() < 1e-9,
+                "{projection:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_perspective_frustum_edges() {
+        let projection = Projection::perspective(FRAC_PI_2, 2.0, 0.5, 100.0);
+        let ndc = projection
+            .matrix()
+            .transform_point(Vector3::new(20.0, 10.0, -10.0));
+        assert!((ndc.x() - 1.0).abs() < 1e-12);
+        assert!((ndc.y() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_project_unproject_round_trip() {
+        let view = Matrix4::look_at(
+            Vector3::new(3.0, 4.0, 10.0),
+            Vector3::new(0.0, 1.0, 0.0),
+            Vector3::Y,
+        )
+        .unwrap();
+        let point = Vector3::new(0.5, 1.5, -1.0);
+        for projection in projections() {
+            let camera = Camera::new(view, projection, Vector2::new(800.0, 600.0)).unwrap();
+            let ndc = camera.project(point);
+            assert_vec_approx_eq!(camera.unproject_ndc(ndc), point, 1e-9);
+            let screen = camera.ndc_to_screen(ndc);
+            assert_vec_approx_eq!(camera.unproject(screen, ndc.z()), point, 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_screen_corners() {
+        let camera = Camera::new(
+            Matrix4::IDENTITY,
+            Projection::orthographic(-4.0, 4.0, -3.0, 3.0, 1.0, 10.0),
+            Vector2::new(800.0, 600.0),
+        )
+        .unwrap();
+        assert_vec_approx_eq!(
+            camera.unproject(Vector2::new(0.0, 0.0), 0.0),
+            Vector3::new(-4.0, 3.0, -1.0)
+        );
+        assert_vec_approx_eq!(
+            camera.unproject(Vector2::new(800.0, 600.0), 1.0),
+            Vector3::new(4.0, -3.0, -10.0)
+        );
+    }
+
+    #[test]
+    fn test_screen_ray() {
+        let eye = Vector3::new(0.0, 0.0, 5.0);
+        let view = Matrix4::look_at(eye, Vector3::ZERO, Vector3::Y).unwrap();
+        let viewport = Vector2::new(800.0, 600.0);
+        let center = Vector2::new(400.0, 300.0);
+        for projection in projections() {
+            let camera = Camera::new(view, projection, viewport).unwrap();
+            let ray = camera.screen_ray(center);
+            assert_vec_approx_eq!(ray.direction, Vector3::NEG_Z, 1e-9);
+            assert!((ray.origin.z() - 4.5).abs() < 1e-9, "{projection:?}");
+
+            let target = Vector3::new(1.0, -0.5, 0.0);
+            let ray = camera.screen_ray(camera.ndc_to_screen(camera.project(target)));
+            let t = (target - ray.origin).dot(ray.direction);
+            assert_vec_approx_eq!(ray.at(t), target, 1e-9);
+        }
+    }
+}
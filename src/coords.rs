@@ -0,0 +1,209 @@
+use crate::math;
+use crate::{Vector2, Vector3};
+
+/// The meaning and order of the two angles in spherical coordinates. The radius always
+/// comes first, and every azimuth is measured counter-clockwise from `+x` towards `+y`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SphericalConvention {
+    /// ISO 80000-2 `(r, theta, phi)`: `theta` is the polar angle from `+z` in `[0, pi]`,
+    /// `phi` is the azimuth in `[-pi, pi]`.
+    Physics,
+    /// `(r, theta, phi)`: `theta` is the azimuth in `[-pi, pi]`, `phi` is the polar angle
+    /// from `+z` in `[0, pi]`.
+    Mathematics,
+    /// Range/azimuth/elevation `(r, azimuth, elevation)`: the azimuth is in `[-pi, pi]`,
+    /// the elevation is measured from the `xy` plane towards `+z` in `[-pi/2, pi/2]`.
+    AzimuthElevation,
+}
+
+impl Vector2 {
+    /// Builds a vector from its length `r` and the angle `theta` from `+x`, in radians.
+    #[inline]
+    #[must_use]
+    pub fn from_polar(r: f64, theta: f64) -> Self {
+        let (sin, cos) = math::sin_cos(theta);
+        Self::new(r * cos, r * sin)
+    }
+
+    /// Returns `(r, theta)`, with `theta` in `[-pi, pi]`.
+    #[inline]
+    #[must_use]
+    pub fn to_polar(self) -> (f64, f64) {
+        (self.magnitude(), math::atan2(self.y(), self.x()))
+    }
+}
+
+impl Vector3 {
+    /// Builds a vector from spherical coordinates whose angles are interpreted according to
+    /// `convention`. Angles are in radians.
+    #[inline]
+    #[must_use]
+    pub fn from_spherical(r: f64, a: f64, b: f64, convention: SphericalConvention) -> Self {
+        match convention {
+            SphericalConvention::Physics => Self::from_polar_azimuth(r, a, b),
+            SphericalConvention::Mathematics => Self::from_polar_azimuth(r, b, a),
+            SphericalConvention::AzimuthElevation => {
+                let (sin_az, cos_az) = math::sin_cos(a);
+                let (sin_el, cos_el) = math::sin_cos(b);
+                let rho = r * cos_el;
+                Self::new(rho * cos_az, rho * sin_az, r * sin_el)
+            }
+        }
+    }
+
+    /// Returns `(r, a, b)` in the order and meaning given by `convention`. The zero vector
+    /// maps to all zeros.
+    #[inline]
+    #[must_use]
+    pub fn to_spherical(self, convention: SphericalConvention) -> (f64, f64, f64) {
+        let rho = math::hypot(self.x(), self.y());
+        let r = self.magnitude();
+        let azimuth = math::atan2(self.y(), self.x());
+        match convention {
+            SphericalConvention::Physics => (r, math::atan2(rho, self.z()), azimuth),
+            SphericalConvention::Mathematics => (r, azimuth, math::atan2(rho, self.z())),
+            SphericalConvention::AzimuthElevation => (r, azimuth, math::atan2(self.z(), rho)),
+        }
+    }
+
+    /// Builds a vector from its distance `rho` to the `z` axis, the azimuth `phi` from `+x`
+    /// in radians, and its height `z`.
+    #[inline]
+    #[must_use]
+    pub fn from_cylindrical(rho: f64, phi: f64, z: f64) -> Self {
+        let (sin, cos) = math::sin_cos(phi);
+        Self::new(rho * cos, rho * sin, z)
+    }
+
+    /// Returns `(rho, phi, z)`, with `phi` in `[-pi, pi]`.
+    #[inline]
+    #[must_use]
+    pub fn to_cylindrical(self) -> (f64, f64, f64) {
+        (
+            math::hypot(self.x(), self.y()),
+            math::atan2(self.y(), self.x()),
+            self.z(),
+        )
+    }
+
+    #[inline]
+    fn from_polar_azimuth(r: f64, polar: f64, azimuth: f64) -> Self {
+        let (sin_p, cos_p) = math::sin_cos(polar);
+        let (sin_a, cos_a) = math::sin_cos(azimuth);
+        let rho = r * sin_p;
+        Self::new(rho * cos_a, rho * sin_a, r * cos_p)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_vec_approx_eq;
+    use core::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    const CONVENTIONS: [SphericalConvention; 3] = [
+        SphericalConvention::Physics,
+        SphericalConvention::Mathematics,
+        SphericalConvention::AzimuthElevation,
+    ];
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-12, "{a} != {b}");
+    }
+
+    #[test]
+    fn test_polar() {
+        assert_vec_approx_eq!(Vector2::from_polar(2.0, FRAC_PI_2), Vector2::new(0.0, 2.0));
+        let (r, theta) = Vector2::new(-1.0, -1.0).to_polar();
+        assert_close(r, 2.0f64.sqrt());
+        assert_close(theta, -3.0 * FRAC_PI_4);
+        assert_eq!(Vector2::zeros().to_polar(), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_polar_round_trip() {
+        let v = Vector2::new(3.0, -4.0);
+        let (r, theta) = v.to_polar();
+        assert_vec_approx_eq!(Vector2::from_polar(r, theta), v);
+    }
+
+    #[test]
+    fn test_from_spherical_axes() {
+        let up = Vector3::new(0.0, 0.0, 2.0);
+        let y = Vector3::new(0.0, 2.0, 0.0);
+        use SphericalConvention::*;
+        assert_vec_approx_eq!(Vector3::from_spherical(2.0, 0.0, 1.0, Physics), up);
+        assert_vec_approx_eq!(
+            Vector3::from_spherical(2.0, FRAC_PI_2, FRAC_PI_2, Physics),
+            y
+        );
+        assert_vec_approx_eq!(Vector3::from_spherical(2.0, 1.0, 0.0, Mathematics), up);
+        assert_vec_approx_eq!(
+            Vector3::from_spherical(2.0, FRAC_PI_2, FRAC_PI_2, Mathematics),
+            y
+        );
+        assert_vec_approx_eq!(
+            Vector3::from_spherical(2.0, 1.0, FRAC_PI_2, AzimuthElevation),
+            up
+        );
+        assert_vec_approx_eq!(
+            Vector3::from_spherical(2.0, FRAC_PI_2, 0.0, AzimuthElevation),
+            y
+        );
+    }
+
+    #[test]
+    fn test_to_spherical() {
+        let v = Vector3::new(1.0, 1.0, 2.0f64.sqrt());
+        let (r, theta, phi) = v.to_spherical(SphericalConvention::Physics);
+        assert_close(r, 2.0);
+        assert_close(theta, FRAC_PI_4);
+        assert_close(phi, FRAC_PI_4);
+        let (r, theta, phi) = v.to_spherical(SphericalConvention::Mathematics);
+        assert_close(r, 2.0);
+        assert_close(theta, FRAC_PI_4);
+        assert_close(phi, FRAC_PI_4);
+
+        let (r, azimuth, elevation) =
+            Vector3::new(-1.0, 0.0, -1.0).to_spherical(SphericalConvention::AzimuthElevation);
+        assert_close(r, 2.0f64.sqrt());
+        assert_close(azimuth, PI);
+        assert_close(elevation, -FRAC_PI_4);
+    }
+
+    #[test]
+    fn test_spherical_zero() {
+        for convention in CONVENTIONS {
+            assert_eq!(Vector3::zeros().to_spherical(convention), (0.0, 0.0, 0.0));
+        }
+    }
+
+    #[test]
+    fn test_spherical_round_trip() {
+        let points = [
+            Vector3::new(1.0, 2.0, 3.0),
+            Vector3::new(-4.0, 0.5, -2.0),
+            Vector3::new(0.0, -1.0, 0.0),
+            Vector3::new(0.0, 0.0, -5.0),
+        ];
+        for convention in CONVENTIONS {
+            for v in points {
+                let (r, a, b) = v.to_spherical(convention);
+                assert_vec_approx_eq!(Vector3::from_spherical(r, a, b, convention), v);
+            }
+        }
+    }
+
+    #[test]
+    fn test_cylindrical() {
+        assert_vec_approx_eq!(
+            Vector3::from_cylindrical(2.0, FRAC_PI_2, -1.0),
+            Vector3::new(0.0, 2.0, -1.0)
+        );
+        let v = Vector3::new(3.0, -4.0, 7.0);
+        let (rho, phi, z) = v.to_cylindrical();
+        assert_close(rho, 5.0);
+        assert_eq!(z, 7.0);
+        assert_vec_approx_eq!(Vector3::from_cylindrical(rho, phi, z), v);
+    }
+}
@@ -0,0 +1,27 @@
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FastVecError {
+    /// The vector has zero length, so it has no direction.
+    ZeroLength,
+    /// The input or the result contains an infinite or NaN component.
+    NonFinite,
+    /// A division had a zero divisor.
+    DivisionByZero,
+    /// A set of vectors that should be independent spans fewer dimensions than expected.
+    LinearlyDependent,
+}
+
+impl Display for FastVecError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ZeroLength => write!(f, "vector has zero length"),
+            Self::NonFinite => write!(f, "vector has a non-finite component or length"),
+            Self::DivisionByZero => write!(f, "division by zero"),
+            Self::LinearlyDependent => write!(f, "vectors are linearly dependent"),
+        }
+    }
+}
+
+impl Error for FastVecError {}
@@ -0,0 +1,328 @@
+use crate::math;
+use crate::{Rotation3, Vector3};
+use core::f64::consts::PI;
+
+/// The axis sequence of an Euler angle triple. The first six are Tait-Bryan sequences, the
+/// last six proper Euler sequences.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EulerOrder {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+    XYX,
+    XZX,
+    YXY,
+    YZY,
+    ZXZ,
+    ZYZ,
+}
+
+/// Whether each rotation of an Euler sequence is about the axes of the rotating body
+/// (intrinsic) or the fixed world axes (extrinsic).
+///
+/// Intrinsic `XYZ` with angles `[a, b, c]` is `Rx(a) * Ry(b) * Rz(c)`; extrinsic `XYZ` is
+/// `Rz(c) * Ry(b) * Rx(a)`, which is the same rotation as intrinsic `ZYX` with `[c, b, a]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EulerFrame {
+    Intrinsic,
+    Extrinsic,
+}
+
+impl EulerOrder {
+    pub const ALL: [Self; 12] = [
+        Self::XYZ,
+        Self::XZY,
+        Self::YXZ,
+        Self::YZX,
+        Self::ZXY,
+        Self::ZYX,
+        Self::XYX,
+        Self::XZX,
+        Self::YXY,
+        Self::YZY,
+        Self::ZXZ,
+        Self::ZYZ,
+    ];
+
+    /// Returns the component indices of the three axes, in order.
+    #[must_use]
+    pub const fn axes(self) -> [usize; 3] {
+        match self {
+            Self::XYZ => [0, 1, 2],
+            Self::XZY => [0, 2, 1],
+            Self::YXZ => [1, 0, 2],
+            Self::YZX => [1, 2, 0],
+            Self::ZXY => [2, 0, 1],
+            Self::ZYX => [2, 1, 0],
+            Self::XYX => [0, 1, 0],
+            Self::XZX => [0, 2, 0],
+            Self::YXY => [1, 0, 1],
+            Self::YZY => [1, 2, 1],
+            Self::ZXZ => [2, 0, 2],
+            Self::ZYZ => [2, 1, 2],
+        }
+    }
+
+    /// Returns the sequence with the axes in reverse order. Proper Euler sequences are their
+    /// own reverse.
+    #[must_use]
+    pub const fn reversed(self) -> Self {
+        match self {
+            Self::XYZ => Self::ZYX,
+            Self::XZY => Self::YZX,
+            Self::YXZ => Self::ZXY,
+            Self::YZX => Self::XZY,
+            Self::ZXY => Self::YXZ,
+            Self::ZYX => Self::XYZ,
+            proper => proper,
+        }
+    }
+
+    #[must_use]
+    pub const fn is_proper_euler(self) -> bool {
+        let [first, _, last] = self.axes();
+        first == last
+    }
+}
+
+#[inline]
+fn rotation_about(axis: usize, angle: f64) -> Rotation3 {
+    match axis {
+        0 => Rotation3::from_rotation_x(angle),
+        1 => Rotation3::from_rotation_y(angle),
+        _ => Rotation3::from_rotation_z(angle),
+    }
+}
+
+#[inline]
+fn wrap_angle(angle: f64) -> f64 {
+    if angle > PI {
+        angle - 2.0 * PI
+    } else if angle < -PI {
+        angle + 2.0 * PI
+    } else {
+        angle
+    }
+}
+
+impl Rotation3 {
+    /// Builds the rotation described by Euler `angles` (in radians) applied in `order`.
+    #[must_use]
+    pub fn from_euler(order: EulerOrder, frame: EulerFrame, angles: [f64; 3]) -> Self {
+        let [i, j, k] = order.axes();
+        let [a, b, c] = angles;
+        let (first, second, third) = (
+            rotation_about(i, a),
+            rotation_about(j, b),
+            rotation_about(k, c),
+        );
+        match frame {
+            EulerFrame::Intrinsic => first * second * third,
+            EulerFrame::Extrinsic => third * second * first,
+        }
+    }
+
+    /// Extracts Euler angles such that `from_euler(order, frame, angles)` reproduces `self`.
+    ///
+    /// The first and last angles are in `[-pi, pi]`. The middle angle is in
+    /// `[-pi/2, pi/2]` for Tait-Bryan orders and `[0, pi]` for proper Euler orders. In gimbal
+    /// lock, where only the sum or difference of the outer angles is defined, the angle
+    /// applied last in the extrinsic sense is set to zero.
+    #[must_use]
+    pub fn to_euler(&self, order: EulerOrder, frame: EulerFrame) -> [f64; 3] {
+        match frame {
+            EulerFrame::Extrinsic => self.extrinsic_euler_angles(order),
+            EulerFrame::Intrinsic => {
+                let [a, b, c] = self.extrinsic_euler_angles(order.reversed());
+                [c, b, a]
+            }
+        }
+    }
+
+    /// Extrinsic extraction following Shoemake, "Euler Angle Conversion" (Graphics Gems IV).
+    fn extrinsic_euler_angles(&self, order: EulerOrder) -> [f64; 3] {
+        const GIMBAL_EPSILON: f64 = 16.0 * f64::EPSILON;
+
+        let m = |row: usize, col: usize| self.col(col)[row];
+        let [i, j, _] = order.axes();
+        let k = 3 - i - j;
+        // +1 if (i, j, k) is a cyclic permutation of (x, y, z), -1 otherwise.
+        let s = if (i + 1) % 3 == j { 1.0 } else { -1.0 };
+
+        if order.is_proper_euler() {
+            let sy = math::hypot(m(i, j), m(i, k));
+            let b = s * math::atan2(sy, m(i, i));
+            let (a, c) = if sy > GIMBAL_EPSILON {
+                (
+                    s * math::atan2(m(i, j), m(i, k)),
+                    s * math::atan2(m(j, i), -m(k, i)),
+                )
+            } else {
+                (s * math::atan2(-m(j, k), m(j, j)), 0.0)
+            };
+            if b < 0.0 {
+                [wrap_angle(a + PI), -b, wrap_angle(c + PI)]
+            } else {
+                [a, b, c]
+            }
+        } else {
+            let cy = math::hypot(m(i, i), m(j, i));
+            let b = s * math::atan2(-m(k, i), cy);
+            if cy > GIMBAL_EPSILON {
+                [
+                    s * math::atan2(m(k, j), m(k, k)),
+                    b,
+                    s * math::atan2(m(j, i), m(i, i)),
+                ]
+            } else {
+                [s * math::atan2(-m(j, k), m(j, j)), b, 0.0]
+            }
+        }
+    }
+}
+
+impl Vector3 {
+    /// Rotates the vector by Euler `angles` (in radians) applied in `order`.
+    #[inline]
+    #[must_use]
+    pub fn rotate_euler(self, order: EulerOrder, frame: EulerFrame, angles: [f64; 3]) -> Self {
+        Rotation3::from_euler(order, frame, angles).rotate(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_vec_approx_eq;
+    use core::f64::consts::FRAC_PI_2;
+
+    const FRAMES: [EulerFrame; 2] = [EulerFrame::Intrinsic, EulerFrame::Extrinsic];
+
+    fn sample_angles() -> Vec<[f64; 3]> {
+        let values = [-3.0, -1.2, -0.4, 0.0, 0.3, 1.1, 2.5];
+        let mut angles = Vec::new();
+        for a in values {
+            for b in values {
+                for c in values {
+                    angles.push([a, b, c]);
+                }
+            }
+        }
+        angles
+    }
+
+    #[test]
+    fn test_intrinsic_and_extrinsic_relationship() {
+        let angles = [0.3, -0.7, 1.9];
+        let intrinsic = Rotation3::from_euler(EulerOrder::XYZ, EulerFrame::Intrinsic, angles);
+        let extrinsic =
+            Rotation3::from_euler(EulerOrder::ZYX, EulerFrame::Extrinsic, [1.9, -0.7, 0.3]);
+        assert!(intrinsic.abs_diff_eq(&extrinsic, 1e-15));
+        let expected = Rotation3::from_rotation_x(0.3)
+            * Rotation3::from_rotation_y(-0.7)
+            * Rotation3::from_rotation_z(1.9);
+        assert!(intrinsic.abs_diff_eq(&expected, 1e-15));
+    }
+
+    #[test]
+    fn test_reversed() {
+        for order in EulerOrder::ALL {
+            let [i, j, k] = order.axes();
+            assert_eq!(order.reversed().axes(), [k, j, i]);
+        }
+    }
+
+    #[test]
+    fn test_rotate_euler() {
+        let v = Vector3::X.rotate_euler(
+            EulerOrder::ZYX,
+            EulerFrame::Intrinsic,
+            [FRAC_PI_2, 0.0, 0.0],
+        );
+        assert_vec_approx_eq!(v, Vector3::Y);
+        let v = Vector3::X.rotate_euler(
+            EulerOrder::XYZ,
+            EulerFrame::Extrinsic,
+            [FRAC_PI_2, FRAC_PI_2, 0.0],
+        );
+        assert_vec_approx_eq!(v, Vector3::NEG_Z);
+    }
+
+    #[test]
+    fn test_round_trip_all_orders() {
+        for order in EulerOrder::ALL {
+            for frame in FRAMES {
+                for angles in sample_angles() {
+                    let rotation = Rotation3::from_euler(order, frame, angles);
+                    let extracted = rotation.to_euler(order, frame);
+                    let rebuilt = Rotation3::from_euler(order, frame, extracted);
+                    assert!(
+                        rebuilt.abs_diff_eq(&rotation, 1e-12),
+                        "{order:?} {frame:?} {angles:?} -> {extracted:?}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_extracted_ranges() {
+        for order in EulerOrder::ALL {
+            for frame in FRAMES {
+                for angles in sample_angles() {
+                    let [a, b, c] =
+                        Rotation3::from_euler(order, frame, angles).to_euler(order, frame);
+                    assert!((-PI..=PI).contains(&a) && (-PI..=PI).contains(&c));
+                    if order.is_proper_euler() {
+                        assert!((0.0..=PI).contains(&b), "{order:?} {b}");
+                    } else {
+                        assert!((-FRAC_PI_2..=FRAC_PI_2).contains(&b), "{order:?} {b}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_recovers_angles_away_from_singularities() {
+        for order in EulerOrder::ALL {
+            for frame in FRAMES {
+                let angles = if order.is_proper_euler() {
+                    [0.4, 1.1, -2.0]
+                } else {
+                    [0.4, -0.9, -2.0]
+                };
+                let extracted = Rotation3::from_euler(order, frame, angles).to_euler(order, frame);
+                for (e, a) in extracted.iter().zip(angles) {
+                    assert!((e - a).abs() < 1e-12, "{order:?} {frame:?} {extracted:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_gimbal_lock() {
+        for order in EulerOrder::ALL {
+            for frame in FRAMES {
+                let middle = if order.is_proper_euler() {
+                    PI
+                } else {
+                    FRAC_PI_2
+                };
+                for b in [middle, -middle, 0.0] {
+                    let rotation = Rotation3::from_euler(order, frame, [0.5, b, 0.25]);
+                    let extracted = rotation.to_euler(order, frame);
+                    assert!(extracted.iter().all(|angle| angle.is_finite()));
+                    let rebuilt = Rotation3::from_euler(order, frame, extracted);
+                    assert!(
+                        rebuilt.abs_diff_eq(&rotation, 1e-12),
+                        "{order:?} {frame:?} {b} -> {extracted:?}"
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+use core::num::ParseFloatError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseVectorError {
+    /// The input is not wrapped in `(...)` or `[...]`.
+    InvalidDelimiters,
+    WrongComponentCount {
+        expected: usize,
+        found: usize,
+    },
+    InvalidComponent(ParseFloatError),
+}
+
+impl Display for ParseVectorError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidDelimiters => {
+                write!(f, "vector must be wrapped in parentheses or brackets")
+            }
+            Self::WrongComponentCount { expected, found } => {
+                write!(f, "expected {expected} components, found {found}")
+            }
+            Self::InvalidComponent(err) => write!(f, "invalid component: {err}"),
+        }
+    }
+}
+
+impl Error for ParseVectorError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::InvalidComponent(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseFloatError> for ParseVectorError {
+    fn from(err: ParseFloatError) -> Self {
+        Self::InvalidComponent(err)
+    }
+}
+
+/// Writes `(c0, c1, ...)`, forwarding the formatter flags to every component.
+pub(crate) fn write_components(
+    f: &mut Formatter<'_>,
+    components: &[f64],
+    fmt_component: fn(&f64, &mut Formatter<'_>) -> fmt::Result,
+) -> fmt::Result {
+    f.write_str("(")?;
+    for (i, component) in components.iter().enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+        fmt_component(component, f)?;
+    }
+    f.write_str(")")
+}
+
+pub(crate) fn parse_components<const N: usize>(s: &str) -> Result<[f64; N], ParseVectorError> {
+    let s = s.trim();
+    let inner = s
+        .strip_prefix('(')
+        .and_then(|rest| rest.strip_suffix(')'))
+        .or_else(|| s.strip_prefix('[').and_then(|rest| rest.strip_suffix(']')))
+        .ok_or(ParseVectorError::InvalidDelimiters)?;
+
+    let found = inner.split(',').count();
+    if found != N {
+        return Err(ParseVectorError::WrongComponentCount { expected: N, found });
+    }
+
+    let mut components = [0.0; N];
+    for (component, part) in components.iter_mut().zip(inner.split(',')) {
+        *component = part.trim().parse()?;
+    }
+    Ok(components)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_parentheses() {
+        assert_eq!(parse_components::<2>("(1, 2)"), Ok([1.0, 2.0]));
+    }
+
+    #[test]
+    fn test_parse_brackets_and_whitespace() {
+        assert_eq!(
+            parse_components::<3>("  [1,2 ,  3 ]  "),
+            Ok([1.0, 2.0, 3.0])
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_delimiters() {
+        assert_eq!(
+            parse_components::<2>("1, 2"),
+            Err(ParseVectorError::InvalidDelimiters)
+        );
+        assert_eq!(
+            parse_components::<2>("(1, 2]"),
+            Err(ParseVectorError::InvalidDelimiters)
+        );
+    }
+
+    #[test]
+    fn test_parse_wrong_component_count() {
+        assert_eq!(
+            parse_components::<3>("(1, 2)"),
+            Err(ParseVectorError::WrongComponentCount {
+                expected: 3,
+                found: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_component() {
+        let err = parse_components::<2>("(1, x)").unwrap_err();
+        assert!(matches!(err, ParseVectorError::InvalidComponent(_)));
+        assert!(err.source().is_some());
+    }
+}
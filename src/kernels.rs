@@ -0,0 +1,436 @@
+//! Slice kernels compiled for several x86-64 feature levels and dispatched at runtime.
+//!
+//! Each kernel is built for the SSE2 baseline and for AVX2 with FMA, and the best version the
+//! CPU supports is picked on first use, so release builds don't need `-C target-cpu=native`.
+//! The kernels transpose four vectors into one `f64x4` per component and work on all four
+//! lanes at once: SSE2 splits each of those operations into two 128-bit instructions, while
+//! the AVX2 build issues one 256-bit instruction. There is no AVX-512 level, because eight
+//! vectors per register measured no faster than four: these kernels are bound by loads and
+//! by square root and division throughput. No kernel uses fused multiply-add explicitly, so
+//! every level produces bit-for-bit identical results. Without `std` (no runtime detection)
+//! or off x86-64, the level is fixed at compile time by the enabled target features.
+
+use crate::backend::{f64x4, simd_swizzle};
+use crate::{Matrix4, Vector3, Vector3x4};
+
+/// An instruction set level a kernel can be dispatched to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum SimdLevel {
+    /// SSE2 on x86-64, or whatever the target enables elsewhere.
+    Baseline,
+    Avx2Fma,
+}
+
+impl SimdLevel {
+    pub const ALL: [Self; 2] = [Self::Baseline, Self::Avx2Fma];
+
+    /// Returns the best level supported by the running CPU. The result is cached after the
+    /// first call.
+    #[must_use]
+    pub fn detect() -> Self {
+        #[cfg(all(feature = "std", target_arch = "x86_64"))]
+        {
+            use core::sync::atomic::{AtomicU8, Ordering};
+
+            static DETECTED: AtomicU8 = AtomicU8::new(u8::MAX);
+            match DETECTED.load(Ordering::Relaxed) {
+                0 => Self::Baseline,
+                1 => Self::Avx2Fma,
+                _ => {
+                    let level = Self::ALL
+                        .into_iter()
+                        .rfind(|level| level.is_supported())
+                        .unwrap_or(Self::Baseline);
+                    DETECTED.store(level as u8, Ordering::Relaxed);
+                    level
+                }
+            }
+        }
+        #[cfg(not(all(feature = "std", target_arch = "x86_64")))]
+        {
+            Self::ALL
+                .into_iter()
+                .rfind(|level| level.is_supported())
+                .unwrap_or(Self::Baseline)
+        }
+    }
+
+    /// Returns `true` if the running CPU can execute kernels built for this level.
+    #[must_use]
+    pub fn is_supported(self) -> bool {
+        match self {
+            Self::Baseline => true,
+            #[cfg(all(feature = "std", target_arch = "x86_64"))]
+            Self::Avx2Fma => {
+                std::is_x86_feature_detected!("avx2") && std::is_x86_feature_detected!("fma")
+            }
+            #[cfg(not(all(feature = "std", target_arch = "x86_64")))]
+            Self::Avx2Fma => cfg!(all(target_feature = "avx2", target_feature = "fma")),
+        }
+    }
+}
+
+/// Calls `$kernel` through a copy compiled for `$level`. Levels above the baseline only
+/// exist on x86-64; elsewhere the baseline kernel is always used.
+macro_rules! dispatch {
+    ($level:expr, $kernel:ident($($arg:ident: $ty:ty),* $(,)?)) => {{
+        #[cfg(target_arch = "x86_64")]
+        {
+            #[target_feature(enable = "avx2,fma")]
+            fn avx2_fma($($arg: $ty),*) {
+                $kernel($($arg),*)
+            }
+
+            let level: SimdLevel = $level;
+            assert!(level.is_supported(), "{level:?} is not supported by this CPU");
+            match level {
+                SimdLevel::Baseline => $kernel($($arg),*),
+                // SAFETY: the assertion above checked that the CPU has these features.
+                SimdLevel::Avx2Fma => unsafe { avx2_fma($($arg),*) },
+            }
+        }
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            let _: SimdLevel = $level;
+            $kernel($($arg),*)
+        }
+    }};
+}
+
+/// Writes `a[i] + b[i]` to `out[i]`.
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+pub fn add(a: &[Vector3], b: &[Vector3], out: &mut [Vector3]) {
+    add_with(SimdLevel::detect(), a, b, out);
+}
+
+/// Like [`add`], using the kernel built for `level`.
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths or `level` is not supported.
+pub fn add_with(level: SimdLevel, a: &[Vector3], b: &[Vector3], out: &mut [Vector3]) {
+    assert!(
+        a.len() == b.len() && a.len() == out.len(),
+        "slice lengths differ"
+    );
+    dispatch!(level, add_kernel(a: &[Vector3], b: &[Vector3], out: &mut [Vector3]));
+}
+
+/// Adds `offset` to every point.
+pub fn translate(points: &mut [Vector3], offset: Vector3) {
+    translate_with(SimdLevel::detect(), points, offset);
+}
+
+/// Like [`translate`], using the kernel built for `level`.
+///
+/// # Panics
+///
+/// Panics if `level` is not supported.
+pub fn translate_with(level: SimdLevel, points: &mut [Vector3], offset: Vector3) {
+    dispatch!(level, translate_kernel(points: &mut [Vector3], offset: Vector3));
+}
+
+/// Multiplies every vector by `factor`.
+pub fn scale(vectors: &mut [Vector3], factor: f64) {
+    scale_with(SimdLevel::detect(), vectors, factor);
+}
+
+/// Like [`scale`], using the kernel built for `level`.
+///
+/// # Panics
+///
+/// Panics if `level` is not supported.
+pub fn scale_with(level: SimdLevel, vectors: &mut [Vector3], factor: f64) {
+    dispatch!(level, scale_kernel(vectors: &mut [Vector3], factor: f64));
+}
+
+/// Writes `a[i].dot(b[i])` to `out[i]`.
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+pub fn dot(a: &[Vector3], b: &[Vector3], out: &mut [f64]) {
+    dot_with(SimdLevel::detect(), a, b, out);
+}
+
+/// Like [`dot`], using the kernel built for `level`.
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths or `level` is not supported.
+pub fn dot_with(level: SimdLevel, a: &[Vector3], b: &[Vector3], out: &mut [f64]) {
+    assert!(
+        a.len() == b.len() && a.len() == out.len(),
+        "slice lengths differ"
+    );
+    dispatch!(level, dot_kernel(a: &[Vector3], b: &[Vector3], out: &mut [f64]));
+}
+
+/// Writes `vectors[i].distance(point)` to `out[i]`.
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+pub fn distance_to(vectors: &[Vector3], point: Vector3, out: &mut [f64]) {
+    distance_to_with(SimdLevel::detect(), vectors, point, out);
+}
+
+/// Like [`distance_to`], using the kernel built for `level`.
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths or `level` is not supported.
+pub fn distance_to_with(level: SimdLevel, vectors: &[Vector3], point: Vector3, out: &mut [f64]) {
+    assert!(vectors.len() == out.len(), "slice lengths differ");
+    dispatch!(level, distance_to_kernel(vectors: &[Vector3], point: Vector3, out: &mut [f64]));
+}
+
+/// Normalizes every vector with [`Vector3::normalize`].
+pub fn normalize(vectors: &mut [Vector3]) {
+    normalize_with(SimdLevel::detect(), vectors);
+}
+
+/// Like [`normalize`], using the kernel built for `level`.
+///
+/// # Panics
+///
+/// Panics if `level` is not supported.
+pub fn normalize_with(level: SimdLevel, vectors: &mut [Vector3]) {
+    dispatch!(level, normalize_kernel(vectors: &mut [Vector3]));
+}
+
+/// Transforms every point with [`Matrix4::transform_point`].
+pub fn transform(matrix: &Matrix4, points: &mut [Vector3]) {
+    transform_with(SimdLevel::detect(), matrix, points);
+}
+
+/// Like [`transform`], using the kernel built for `level`.
+///
+/// # Panics
+///
+/// Panics if `level` is not supported.
+pub fn transform_with(level: SimdLevel, matrix: &Matrix4, points: &mut [Vector3]) {
+    dispatch!(level, transform_kernel(matrix: &Matrix4, points: &mut [Vector3]));
+}
+
+#[inline(always)]
+fn add_kernel(a: &[Vector3], b: &[Vector3], out: &mut [Vector3]) {
+    for ((o, a), b) in out.iter_mut().zip(a).zip(b) {
+        *o = *a + *b;
+    }
+}
+
+#[inline(always)]
+fn translate_kernel(points: &mut [Vector3], offset: Vector3) {
+    for p in points {
+        *p += offset;
+    }
+}
+
+#[inline(always)]
+fn scale_kernel(vectors: &mut [Vector3], factor: f64) {
+    for v in vectors {
+        *v *= factor;
+    }
+}
+
+#[inline(always)]
+fn dot_kernel(a: &[Vector3], b: &[Vector3], out: &mut [f64]) {
+    let mut out_chunks = out.chunks_exact_mut(4);
+    let mut a_chunks = a.chunks_exact(4);
+    let mut b_chunks = b.chunks_exact(4);
+    for ((o, a), b) in (&mut out_chunks).zip(&mut a_chunks).zip(&mut b_chunks) {
+        // One product per vector, transposed so that lane `i` of `p[j]` is the `j`th product of
+        // vector `i`, then summed in the same order as `Vector3::dot`.
+        let p = transpose(core::array::from_fn(|i| a[i].to_simd() * b[i].to_simd()));
+        let dots = p[0] + p[1] + p[2] + p[3];
+        o.copy_from_slice(dots.as_array());
+    }
+    let tails = a_chunks.remainder().iter().zip(b_chunks.remainder());
+    for (o, (a, b)) in out_chunks.into_remainder().iter_mut().zip(tails) {
+        *o = a.dot(*b);
+    }
+}
+
+#[inline(always)]
+fn distance_to_kernel(vectors: &[Vector3], point: Vector3, out: &mut [f64]) {
+    let point4 = Vector3x4::splat(point);
+    let mut out_chunks = out.chunks_exact_mut(4);
+    let mut chunks = vectors.chunks_exact(4);
+    for (o, chunk) in (&mut out_chunks).zip(&mut chunks) {
+        o.copy_from_slice(&load(chunk).distance(point4));
+    }
+    for (o, v) in out_chunks
+        .into_remainder()
+        .iter_mut()
+        .zip(chunks.remainder())
+    {
+        *o = v.distance(point);
+    }
+}
+
+#[inline(always)]
+fn normalize_kernel(vectors: &mut [Vector3]) {
+    let mut chunks = vectors.chunks_exact_mut(4);
+    for chunk in &mut chunks {
+        store(load(chunk).normalize(), chunk);
+    }
+    for v in chunks.into_remainder() {
+        *v = v.normalize();
+    }
+}
+
+#[inline(always)]
+fn transform_kernel(matrix: &Matrix4, points: &mut [Vector3]) {
+    let mut chunks = points.chunks_exact_mut(4);
+    for chunk in &mut chunks {
+        store(matrix.transform_point_packet(load(chunk)), chunk);
+    }
+    for p in chunks.into_remainder() {
+        *p = matrix.transform_point(*p);
+    }
+}
+
+/// Loads four vectors into a packet. `chunk` must hold exactly four vectors.
+#[inline(always)]
+fn load(chunk: &[Vector3]) -> Vector3x4 {
+    let [x, y, z, _] = transpose(core::array::from_fn(|i| chunk[i].to_simd()));
+    Vector3x4 { x, y, z }
+}
+
+/// Writes a packet's four vectors to `chunk`, which must hold exactly four vectors.
+#[inline(always)]
+fn store(packet: Vector3x4, chunk: &mut [Vector3]) {
+    let rows = transpose([packet.x, packet.y, packet.z, f64x4::splat(0.0)]);
+    for (v, row) in chunk.iter_mut().zip(rows) {
+        *v = Vector3::from_simd(row);
+    }
+}
+
+/// Transposes a 4x4 block, turning four vectors' lanes into one `f64x4` per lane index. The
+/// shuffles stay in registers, unlike indexing through arrays.
+#[inline(always)]
+fn transpose([r0, r1, r2, r3]: [f64x4; 4]) -> [f64x4; 4] {
+    let t0: f64x4 = simd_swizzle!(r0, r1, [0, 4, 2, 6]);
+    let t1: f64x4 = simd_swizzle!(r0, r1, [1, 5, 3, 7]);
+    let t2: f64x4 = simd_swizzle!(r2, r3, [0, 4, 2, 6]);
+    let t3: f64x4 = simd_swizzle!(r2, r3, [1, 5, 3, 7]);
+    [
+        simd_swizzle!(t0, t2, [0, 1, 4, 5]),
+        simd_swizzle!(t1, t3, [0, 1, 4, 5]),
+        simd_swizzle!(t0, t2, [2, 3, 6, 7]),
+        simd_swizzle!(t1, t3, [2, 3, 6, 7]),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::sample_vectors;
+
+    fn supported_levels() -> impl Iterator<Item = SimdLevel> {
+        SimdLevel::ALL
+            .into_iter()
+            .filter(|level| level.is_supported())
+    }
+
+    #[test]
+    fn test_detect_is_supported() {
+        assert!(SimdLevel::detect().is_supported());
+        assert!(SimdLevel::Baseline.is_supported());
+        assert_eq!(SimdLevel::detect(), SimdLevel::detect());
+    }
+
+    #[test]
+    fn test_levels_match_scalar_results() {
+        // Odd length so that no level processes only whole vectors' worth of lanes.
+        let a = sample_vectors(37, 0.0);
+        let b = sample_vectors(37, 100.0);
+        let matrix =
+            Matrix4::look_at(Vector3::new(1.0, 2.0, 3.0), Vector3::ZERO, Vector3::Y).unwrap();
+
+        for level in supported_levels() {
+            let mut sums = vec![Vector3::ZERO; a.len()];
+            add_with(level, &a, &b, &mut sums);
+            let mut dots = vec![0.0; a.len()];
+            dot_with(level, &a, &b, &mut dots);
+            let mut translated = a.clone();
+            translate_with(level, &mut translated, b[0]);
+            let mut scaled = a.clone();
+            scale_with(level, &mut scaled, -2.5);
+            let mut distances = vec![0.0; a.len()];
+            distance_to_with(level, &a, b[0], &mut distances);
+            let mut normalized = a.clone();
+            normalize_with(level, &mut normalized);
+            let mut transformed = a.clone();
+            transform_with(level, &matrix, &mut transformed);
+
+            for i in 0..a.len() {
+                assert_eq!(sums[i], a[i] + b[i], "{level:?}");
+                assert_eq!(dots[i].to_bits(), a[i].dot(b[i]).to_bits(), "{level:?}");
+                assert_eq!(translated[i], a[i] + b[0], "{level:?}");
+                assert_eq!(scaled[i], a[i] * -2.5, "{level:?}");
+                let distance = a[i].distance(b[0]);
+                assert_eq!(distances[i].to_bits(), distance.to_bits(), "{level:?}");
+                assert_eq!(normalized[i], a[i].normalize(), "{level:?}");
+                assert_eq!(transformed[i], matrix.transform_point(a[i]), "{level:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_dot_zero_sign_matches() {
+        // The component products are -0.0, and the padding lanes' +0.0 makes every sum +0.0.
+        let a = [Vector3::ZERO, -Vector3::ZERO, Vector3::ZERO, -Vector3::ZERO];
+        let b = [-Vector3::ONE, -Vector3::ONE, Vector3::NEG_X, Vector3::ONE];
+        for level in supported_levels() {
+            let mut out = [0.0; 4];
+            dot_with(level, &a, &b, &mut out);
+            for i in 0..4 {
+                assert_eq!(out[i].to_bits(), a[i].dot(b[i]).to_bits(), "{level:?} {i}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_normalize_zero_length_bits() {
+        let tiny = Vector3::new(-0.0, -1e-200, 0.0);
+        for level in supported_levels() {
+            let mut vectors = [tiny; 4];
+            normalize_with(level, &mut vectors);
+            for v in vectors {
+                assert_eq!(v.to_array().map(f64::to_bits), [0; 3], "{level:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_detected_dispatch() {
+        let a = sample_vectors(5, 1.0);
+        let mut out = vec![0.0; 5];
+        dot(&a, &a, &mut out);
+        for (o, v) in out.iter().zip(&a) {
+            assert_eq!(*o, v.magnitude_squared());
+        }
+        let mut empty: [Vector3; 0] = [];
+        normalize(&mut empty);
+    }
+
+    #[test]
+    #[should_panic(expected = "slice lengths differ")]
+    fn test_length_mismatch() {
+        let a = sample_vectors(3, 0.0);
+        let mut out = vec![Vector3::ZERO; 2];
+        add(&a, &a, &mut out);
+    }
+
+    #[test]
+    #[should_panic(expected = "slice lengths differ")]
+    fn test_distance_to_length_mismatch() {
+        let a = sample_vectors(5, 0.0);
+        distance_to(&a, Vector3::ZERO, &mut [0.0; 4]);
+    }
+}
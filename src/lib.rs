@@ -1,8 +1,44 @@
-#![feature(portable_simd)]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+#![cfg_attr(not(feature = "scalar-math"), feature(portable_simd))]
 
+mod approx;
+mod axes;
+mod backend;
+pub mod batch;
+mod camera;
+mod coords;
+mod error;
+mod euler;
+mod format;
+pub mod kernels;
+mod math;
+mod matrix;
+mod ordered;
+mod packed;
+mod packet;
+#[cfg(feature = "rayon")]
+pub mod par;
+mod rotation;
+#[cfg(feature = "std")]
+mod soa;
+#[cfg(test)]
+mod test_util;
 mod vec2;
 mod vec3;
 
+pub use axes::{Axis, AxisConvention, AxisConversion};
+pub use camera::{Camera, Projection, Ray};
+pub use coords::SphericalConvention;
+pub use error::FastVecError;
+pub use euler::{EulerFrame, EulerOrder};
+pub use format::ParseVectorError;
+pub use matrix::Matrix4;
+pub use ordered::{OrderedVector2, OrderedVector3};
+pub use packed::PackedVector3;
+pub use packet::{Vector2x4, Vector3x4};
+pub use rotation::Rotation3;
+#[cfg(feature = "std")]
+pub use soa::{Vector3Soa, Vector3SoaIter};
 pub use vec2::Vector2;
 pub use vec3::Vector3;
 
@@ -0,0 +1,177 @@
+//! Float functions that `core` does not provide, taken from `std` or, in `no_std` builds,
+//! from `libm`.
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("fast-vec needs either the `std` or the `libm` feature for float math");
+
+#[cfg(feature = "std")]
+mod imp {
+    #[inline]
+    pub(crate) fn sqrt(x: f64) -> f64 {
+        x.sqrt()
+    }
+
+    #[inline]
+    pub(crate) fn sin_cos(x: f64) -> (f64, f64) {
+        x.sin_cos()
+    }
+
+    #[inline]
+    pub(crate) fn tan(x: f64) -> f64 {
+        x.tan()
+    }
+
+    #[inline]
+    pub(crate) fn atan2(y: f64, x: f64) -> f64 {
+        y.atan2(x)
+    }
+
+    #[inline]
+    pub(crate) fn hypot(x: f64, y: f64) -> f64 {
+        x.hypot(y)
+    }
+
+    #[inline]
+    pub(crate) fn exp(x: f64) -> f64 {
+        x.exp()
+    }
+
+    #[inline]
+    pub(crate) fn ln(x: f64) -> f64 {
+        x.ln()
+    }
+
+    #[inline]
+    pub(crate) fn powf(x: f64, exponent: f64) -> f64 {
+        x.powf(exponent)
+    }
+
+    #[inline]
+    pub(crate) fn mul_add(x: f64, a: f64, b: f64) -> f64 {
+        x.mul_add(a, b)
+    }
+}
+
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+mod imp {
+    #[inline]
+    pub(crate) fn sqrt(x: f64) -> f64 {
+        libm::sqrt(x)
+    }
+
+    #[inline]
+    pub(crate) fn sin_cos(x: f64) -> (f64, f64) {
+        libm::sincos(x)
+    }
+
+    #[inline]
+    pub(crate) fn tan(x: f64) -> f64 {
+        libm::tan(x)
+    }
+
+    #[inline]
+    pub(crate) fn atan2(y: f64, x: f64) -> f64 {
+        libm::atan2(y, x)
+    }
+
+    #[inline]
+    pub(crate) fn hypot(x: f64, y: f64) -> f64 {
+        libm::hypot(x, y)
+    }
+
+    #[inline]
+    pub(crate) fn exp(x: f64) -> f64 {
+        libm::exp(x)
+    }
+
+    #[inline]
+    pub(crate) fn ln(x: f64) -> f64 {
+        libm::log(x)
+    }
+
+    #[inline]
+    pub(crate) fn powf(x: f64, exponent: f64) -> f64 {
+        libm::pow(x, exponent)
+    }
+
+    #[inline]
+    pub(crate) fn mul_add(x: f64, a: f64, b: f64) -> f64 {
+        libm::fma(x, a, b)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+pub(crate) use imp::*;
+
+/// Returns `a * b - c * d` within 1.5 ulps, using Kahan's algorithm with two fused
+/// multiply-adds to recover the rounding error of `c * d`.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[inline]
+pub(crate) fn difference_of_products(a: f64, b: f64, c: f64, d: f64) -> f64 {
+    let cd = c * d;
+    let error = mul_add(-c, d, cd);
+    mul_add(a, b, -cd) + error
+}
+
+/// Estimates `1 / sqrt(x)` in `f32` precision with the CPU's reciprocal square root
+/// instruction, or a bit trick on targets without one.
+#[inline]
+fn rsqrt_estimate_f32(x: f32) -> f32 {
+    #[cfg(target_arch = "x86_64")]
+    {
+        use core::arch::x86_64::{_mm_cvtss_f32, _mm_rsqrt_ss, _mm_set_ss};
+        // SAFETY: SSE is part of the x86-64 baseline.
+        unsafe { _mm_cvtss_f32(_mm_rsqrt_ss(_mm_set_ss(x))) }
+    }
+    #[cfg(target_arch = "aarch64")]
+    {
+        // SAFETY: NEON is part of the AArch64 baseline. Newer toolchains mark this intrinsic
+        // safe, hence the `allow`.
+        #[allow(unused_unsafe)]
+        unsafe {
+            core::arch::aarch64::vrsqrtes_f32(x)
+        }
+    }
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+    {
+        f32::from_bits(0x5f37_5a86 - (x.to_bits() >> 1))
+    }
+}
+
+/// Estimates `1 / sqrt(x)` for a positive normal `x` outside the `f32` range.
+#[cold]
+fn rsqrt_estimate_wide(x: f64) -> f64 {
+    // Split x into m * 2^(2k) with m in [1, 4), which fits in an f32, so
+    // 1 / sqrt(x) = 1 / sqrt(m) * 2^-k.
+    const EXPONENT_BIAS: i64 = 1023;
+    const MANTISSA_BITS: u32 = 52;
+    let bits = x.to_bits();
+    let exponent = (bits >> MANTISSA_BITS) as i64 - EXPONENT_BIAS;
+    let k = exponent.div_euclid(2);
+    let m = f64::from_bits(
+        (bits & ((1 << MANTISSA_BITS) - 1))
+            | (((exponent - 2 * k + EXPONENT_BIAS) as u64) << MANTISSA_BITS),
+    );
+    let scale = f64::from_bits(((EXPONENT_BIAS - k) as u64) << MANTISSA_BITS);
+    f64::from(rsqrt_estimate_f32(m as f32)) * scale
+}
+
+/// Approximates `1 / sqrt(x)` from a hardware estimate refined by `steps` Newton-Raphson
+/// iterations. Zero, subnormal, infinite and NaN inputs take the exact path.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[inline]
+pub(crate) fn rsqrt_fast(x: f64, steps: u32) -> f64 {
+    let mut y = if (f64::from(f32::MIN_POSITIVE)..=f64::from(f32::MAX)).contains(&x) {
+        f64::from(rsqrt_estimate_f32(x as f32))
+    } else if x.is_normal() && x > 0.0 {
+        rsqrt_estimate_wide(x)
+    } else {
+        return 1.0 / sqrt(x);
+    };
+
+    let half_x = 0.5 * x;
+    for _ in 0..steps {
+        y *= 1.5 - half_x * y * y;
+    }
+    y
+}
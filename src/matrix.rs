@@ -0,0 +1,265 @@
+use crate::backend::f64x4;
+use crate::{Vector3, Vector3x4};
+
+/// A 4x4 column-major matrix acting on column vectors, used for affine and projective
+/// transforms of `Vector3` points.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Matrix4 {
+    cols: [[f64; 4]; 4],
+}
+
+impl Matrix4 {
+    pub const IDENTITY: Self = Self::from_cols([
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]);
+
+    #[inline]
+    #[must_use]
+    pub const fn from_cols(cols: [[f64; 4]; 4]) -> Self {
+        Self { cols }
+    }
+
+    /// Builds a matrix from rows, which reads like the usual mathematical notation.
+    #[must_use]
+    pub const fn from_rows(rows: [[f64; 4]; 4]) -> Self {
+        let mut cols = [[0.0; 4]; 4];
+        let mut row = 0;
+        while row < 4 {
+            let mut col = 0;
+            while col < 4 {
+                cols[col][row] = rows[row][col];
+                col += 1;
+            }
+            row += 1;
+        }
+        Self { cols }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn to_cols(self) -> [[f64; 4]; 4] {
+        self.cols
+    }
+
+    /// Returns the element at `row` and `col`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row` or `col` is greater than 3.
+    #[inline]
+    #[must_use]
+    pub const fn get(&self, row: usize, col: usize) -> f64 {
+        self.cols[col][row]
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn transform4(&self, v: [f64; 4]) -> [f64; 4] {
+        let mut out = [0.0; 4];
+        for (col, component) in self.cols.iter().zip(v) {
+            for (o, c) in out.iter_mut().zip(col) {
+                *o += c * component;
+            }
+        }
+        out
+    }
+
+    /// Transforms `point` with an implicit `w` of 1, then divides by the resulting `w`.
+    #[inline]
+    #[must_use]
+    pub fn transform_point(&self, point: Vector3) -> Vector3 {
+        let [x, y, z, w] = self.transform4([point.x(), point.y(), point.z(), 1.0]);
+        Vector3::new(x / w, y / w, z / w)
+    }
+
+    /// [`transform_point`](Self::transform_point) for the four points of a packet, with the
+    /// same operations in the same order.
+    #[inline]
+    pub(crate) fn transform_point_packet(&self, points: Vector3x4) -> Vector3x4 {
+        let mut out = [f64x4::splat(0.0); 4];
+        let components = [points.x, points.y, points.z, f64x4::splat(1.0)];
+        for (col, component) in self.cols.iter().zip(components) {
+            for (o, c) in out.iter_mut().zip(col) {
+                *o += f64x4::splat(*c) * component;
+            }
+        }
+        let [x, y, z, w] = out;
+        Vector3x4 {
+            x: x / w,
+            y: y / w,
+            z: z / w,
+        }
+    }
+
+    /// Transforms `vector` with an implicit `w` of 0, ignoring translation.
+    #[inline]
+    #[must_use]
+    pub fn transform_vector(&self, vector: Vector3) -> Vector3 {
+        let [x, y, z, _] = self.transform4([vector.x(), vector.y(), vector.z(), 0.0]);
+        Vector3::new(x, y, z)
+    }
+
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        Self::from_rows(self.cols)
+    }
+
+    #[must_use]
+    pub fn determinant(&self) -> f64 {
+        let (_, det) = self.adjugate();
+        det
+    }
+
+    /// Returns the inverse, or `None` if the matrix is singular.
+    #[must_use]
+    pub fn inverse(&self) -> Option<Self> {
+        let (adjugate, det) = self.adjugate();
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+        let inv_det = 1.0 / det;
+        Some(Self::from_cols(
+            adjugate.cols.map(|col| col.map(|c| c * inv_det)),
+        ))
+    }
+
+    #[must_use]
+    pub fn abs_diff_eq(&self, rhs: &Self, epsilon: f64) -> bool {
+        self.cols
+            .iter()
+            .flatten()
+            .zip(rhs.cols.iter().flatten())
+            .all(|(a, b)| (a - b).abs() <= epsilon)
+    }
+
+    /// Returns the adjugate and the determinant, via 2x2 sub-determinants of the top and
+    /// bottom row pairs.
+    fn adjugate(&self) -> (Self, f64) {
+        let m = |row: usize, col: usize| self.cols[col][row];
+
+        let s0 = m(0, 0) * m(1, 1) - m(1, 0) * m(0, 1);
+        let s1 = m(0, 0) * m(1, 2) - m(1, 0) * m(0, 2);
+        let s2 = m(0, 0) * m(1, 3) - m(1, 0) * m(0, 3);
+        let s3 = m(0, 1) * m(1, 2) - m(1, 1) * m(0, 2);
+        let s4 = m(0, 1) * m(1, 3) - m(1, 1) * m(0, 3);
+        let s5 = m(0, 2) * m(1, 3) - m(1, 2) * m(0, 3);
+
+        let c5 = m(2, 2) * m(3, 3) - m(3, 2) * m(2, 3);
+        let c4 = m(2, 1) * m(3, 3) - m(3, 1) * m(2, 3);
+        let c3 = m(2, 1) * m(3, 2) - m(3, 1) * m(2, 2);
+        let c2 = m(2, 0) * m(3, 3) - m(3, 0) * m(2, 3);
+        let c1 = m(2, 0) * m(3, 2) - m(3, 0) * m(2, 2);
+        let c0 = m(2, 0) * m(3, 1) - m(3, 0) * m(2, 1);
+
+        let det = s0 * c5 - s1 * c4 + s2 * c3 + s3 * c2 - s4 * c1 + s5 * c0;
+
+        let adjugate = Self::from_rows([
+            [
+                m(1, 1) * c5 - m(1, 2) * c4 + m(1, 3) * c3,
+                -m(0, 1) * c5 + m(0, 2) * c4 - m(0, 3) * c3,
+                m(3, 1) * s5 - m(3, 2) * s4 + m(3, 3) * s3,
+                -m(2, 1) * s5 + m(2, 2) * s4 - m(2, 3) * s3,
+            ],
+            [
+                -m(1, 0) * c5 + m(1, 2) * c2 - m(1, 3) * c1,
+                m(0, 0) * c5 - m(0, 2) * c2 + m(0, 3) * c1,
+                -m(3, 0) * s5 + m(3, 2) * s2 - m(3, 3) * s1,
+                m(2, 0) * s5 - m(2, 2) * s2 + m(2, 3) * s1,
+            ],
+            [
+                m(1, 0) * c4 - m(1, 1) * c2 + m(1, 3) * c0,
+                -m(0, 0) * c4 + m(0, 1) * c2 - m(0, 3) * c0,
+                m(3, 0) * s4 - m(3, 1) * s2 + m(3, 3) * s0,
+                -m(2, 0) * s4 + m(2, 1) * s2 - m(2, 3) * s0,
+            ],
+            [
+                -m(1, 0) * c3 + m(1, 1) * c1 - m(1, 2) * c0,
+                m(0, 0) * c3 - m(0, 1) * c1 + m(0, 2) * c0,
+                -m(3, 0) * s3 + m(3, 1) * s1 - m(3, 2) * s0,
+                m(2, 0) * s3 - m(2, 1) * s1 + m(2, 2) * s0,
+            ],
+        ]);
+        (adjugate, det)
+    }
+}
+
+impl Default for Matrix4 {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl core::ops::Mul for Matrix4 {
+    type Output = Matrix4;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::from_cols(rhs.cols.map(|col| self.transform4(col)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Matrix4 {
+        Matrix4::from_rows([
+            [2.0, 0.5, -1.0, 3.0],
+            [0.0, 1.5, 2.0, -1.0],
+            [1.0, -2.0, 3.0, 0.5],
+            [0.25, 0.0, 1.0, 2.0],
+        ])
+    }
+
+    #[test]
+    fn test_from_rows() {
+        let m = sample();
+        assert_eq!(m.get(0, 3), 3.0);
+        assert_eq!(m.get(3, 0), 0.25);
+        assert_eq!(m.transpose().get(0, 3), 0.25);
+    }
+
+    #[test]
+    fn test_mul() {
+        let m = sample();
+        assert_eq!(m * Matrix4::IDENTITY, m);
+        assert_eq!(Matrix4::IDENTITY * m, m);
+        let v = [1.0, -2.0, 0.5, 1.0];
+        let product = (m * m).transform4(v);
+        let expected = m.transform4(m.transform4(v));
+        assert_eq!(product, expected);
+    }
+
+    #[test]
+    fn test_inverse() {
+        let m = sample();
+        let inverse = m.inverse().unwrap();
+        assert!((m * inverse).abs_diff_eq(&Matrix4::IDENTITY, 1e-12));
+        assert!((inverse * m).abs_diff_eq(&Matrix4::IDENTITY, 1e-12));
+        assert!((m.determinant() * inverse.determinant() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_inverse_singular() {
+        let mut cols = sample().to_cols();
+        cols[2] = cols[0];
+        assert_eq!(Matrix4::from_cols(cols).determinant(), 0.0);
+        assert!(Matrix4::from_cols(cols).inverse().is_none());
+    }
+
+    #[test]
+    fn test_transform_point_and_vector() {
+        let translation = Matrix4::from_rows([
+            [1.0, 0.0, 0.0, 1.0],
+            [0.0, 1.0, 0.0, 2.0],
+            [0.0, 0.0, 1.0, 3.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+        let v = Vector3::new(1.0, 1.0, 1.0);
+        assert_eq!(translation.transform_point(v), Vector3::new(2.0, 3.0, 4.0));
+        assert_eq!(translation.transform_vector(v), v);
+    }
+}
@@ -0,0 +1,368 @@
+use crate::{Vector2, Vector3};
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+/// Maps `-0.0` to `0.0` and every NaN to `f64::NAN`, so that values that compare
+/// equal also share a bit pattern.
+#[inline]
+fn canonicalize(value: f64) -> f64 {
+    if value.is_nan() {
+        f64::NAN
+    } else if value == 0.0 {
+        0.0
+    } else {
+        value
+    }
+}
+
+#[inline]
+fn cmp_components(lhs: impl Iterator<Item = f64>, rhs: impl Iterator<Item = f64>) -> Ordering {
+    lhs.zip(rhs)
+        .map(|(a, b)| canonicalize(a).total_cmp(&canonicalize(b)))
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or(Ordering::Equal)
+}
+
+/// A `Vector2` that implements `Eq`, `Ord` and `Hash`, ordering components
+/// lexicographically with `f64::total_cmp`.
+///
+/// `-0.0` and `0.0` are treated as the same value, as are all NaNs. NaN sorts after
+/// positive infinity.
+#[derive(Debug, Clone, Copy)]
+pub struct OrderedVector2(pub Vector2);
+
+/// A `Vector3` that implements `Eq`, `Ord` and `Hash`, ordering components
+/// lexicographically with `f64::total_cmp`.
+///
+/// `-0.0` and `0.0` are treated as the same value, as are all NaNs. NaN sorts after
+/// positive infinity.
+#[derive(Debug, Clone, Copy)]
+pub struct OrderedVector3(pub Vector3);
+
+impl OrderedVector2 {
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> Vector2 {
+        self.0
+    }
+}
+
+impl OrderedVector3 {
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> Vector3 {
+        self.0
+    }
+}
+
+impl From<Vector2> for OrderedVector2 {
+    #[inline]
+    fn from(v: Vector2) -> Self {
+        Self(v)
+    }
+}
+
+impl From<Vector3> for OrderedVector3 {
+    #[inline]
+    fn from(v: Vector3) -> Self {
+        Self(v)
+    }
+}
+
+impl From<OrderedVector2> for Vector2 {
+    #[inline]
+    fn from(v: OrderedVector2) -> Self {
+        v.0
+    }
+}
+
+impl From<OrderedVector3> for Vector3 {
+    #[inline]
+    fn from(v: OrderedVector3) -> Self {
+        v.0
+    }
+}
+
+impl PartialEq for OrderedVector2 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl PartialEq for OrderedVector3 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl Eq for OrderedVector2 {}
+
+impl Eq for OrderedVector3 {}
+
+impl PartialOrd for OrderedVector2 {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialOrd for OrderedVector3 {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrderedVector2 {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_components(self.0.iter(), other.0.iter())
+    }
+}
+
+impl Ord for OrderedVector3 {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_components(self.0.iter(), other.0.iter())
+    }
+}
+
+impl Hash for OrderedVector2 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0
+            .to_array()
+            .map(|c| canonicalize(c).to_bits())
+            .hash(state);
+    }
+}
+
+impl Hash for OrderedVector3 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0
+            .to_array()
+            .map(|c| canonicalize(c).to_bits())
+            .hash(state);
+    }
+}
+
+impl Vector2 {
+    /// Sorts `slice` lexicographically by `(x, y)` using the ordering of [`OrderedVector2`].
+    /// The sort is unstable and does not allocate.
+    pub fn sort_lexicographic_unstable(slice: &mut [Self]) {
+        slice.sort_unstable_by_key(|v| OrderedVector2(*v));
+    }
+
+    /// Sorts `slice` by a single component using the ordering of [`OrderedVector2`].
+    /// The sort is unstable and does not allocate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `axis` is not 0 or 1.
+    pub fn sort_by_axis_unstable(slice: &mut [Self], axis: usize) {
+        assert!(axis < 2, "axis {axis} out of range for Vector2");
+        slice.sort_unstable_by(|a, b| canonicalize(a[axis]).total_cmp(&canonicalize(b[axis])));
+    }
+}
+
+// Stable sorting allocates, so it is only available with `std`.
+#[cfg(feature = "std")]
+impl Vector2 {
+    /// Sorts `slice` lexicographically by `(x, y)` using the ordering of [`OrderedVector2`].
+    pub fn sort_lexicographic(slice: &mut [Self]) {
+        slice.sort_by_key(|v| OrderedVector2(*v));
+    }
+
+    /// Sorts `slice` by a single component using the ordering of [`OrderedVector2`].
+    /// The sort is stable.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `axis` is not 0 or 1.
+    pub fn sort_by_axis(slice: &mut [Self], axis: usize) {
+        assert!(axis < 2, "axis {axis} out of range for Vector2");
+        slice.sort_by(|a, b| canonicalize(a[axis]).total_cmp(&canonicalize(b[axis])));
+    }
+}
+
+impl Vector3 {
+    /// Sorts `slice` lexicographically by `(x, y, z)` using the ordering of [`OrderedVector3`].
+    /// The sort is unstable and does not allocate.
+    pub fn sort_lexicographic_unstable(slice: &mut [Self]) {
+        slice.sort_unstable_by_key(|v| OrderedVector3(*v));
+    }
+
+    /// Sorts `slice` by a single component using the ordering of [`OrderedVector3`].
+    /// The sort is unstable and does not allocate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `axis` is not 0, 1 or 2.
+    pub fn sort_by_axis_unstable(slice: &mut [Self], axis: usize) {
+        assert!(axis < 3, "axis {axis} out of range for Vector3");
+        slice.sort_unstable_by(|a, b| canonicalize(a[axis]).total_cmp(&canonicalize(b[axis])));
+    }
+}
+
+// Stable sorting allocates, so it is only available with `std`.
+#[cfg(feature = "std")]
+impl Vector3 {
+    /// Sorts `slice` lexicographically by `(x, y, z)` using the ordering of [`OrderedVector3`].
+    pub fn sort_lexicographic(slice: &mut [Self]) {
+        slice.sort_by_key(|v| OrderedVector3(*v));
+    }
+
+    /// Sorts `slice` by a single component using the ordering of [`OrderedVector3`].
+    /// The sort is stable.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `axis` is not 0, 1 or 2.
+    pub fn sort_by_axis(slice: &mut [Self], axis: usize) {
+        assert!(axis < 3, "axis {axis} out of range for Vector3");
+        slice.sort_by(|a, b| canonicalize(a[axis]).total_cmp(&canonicalize(b[axis])));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::{BTreeSet, HashSet};
+
+    #[test]
+    fn test_eq_canonicalizes_zero_and_nan() {
+        assert_eq!(
+            OrderedVector3(Vector3::new(-0.0, 1.0, 2.0)),
+            OrderedVector3(Vector3::new(0.0, 1.0, 2.0))
+        );
+        let quiet = f64::NAN;
+        let negative = -f64::NAN;
+        assert_eq!(
+            OrderedVector2(Vector2::new(quiet, 1.0)),
+            OrderedVector2(Vector2::new(negative, 1.0))
+        );
+        assert_ne!(
+            OrderedVector2(Vector2::new(1.0, 2.0)),
+            OrderedVector2(Vector2::new(1.0, 3.0))
+        );
+    }
+
+    #[test]
+    fn test_hash_dedup() {
+        let points = [
+            Vector3::new(1.0, 2.0, 3.0),
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(1.0, 2.0, 3.0),
+            Vector3::new(-0.0, 0.0, -0.0),
+            Vector3::new(f64::NAN, 0.0, 0.0),
+            Vector3::new(-f64::NAN, 0.0, 0.0),
+        ];
+        let unique: HashSet<OrderedVector3> = points.into_iter().map(OrderedVector3).collect();
+        assert_eq!(unique.len(), 3);
+
+        let unique: HashSet<OrderedVector2> = [Vector2::new(-0.0, 1.0), Vector2::new(0.0, 1.0)]
+            .into_iter()
+            .map(OrderedVector2)
+            .collect();
+        assert_eq!(unique.len(), 1);
+    }
+
+    #[test]
+    fn test_ord() {
+        let set: BTreeSet<OrderedVector2> = [
+            Vector2::new(f64::NAN, 0.0),
+            Vector2::new(1.0, 2.0),
+            Vector2::new(f64::INFINITY, 0.0),
+            Vector2::new(1.0, -1.0),
+            Vector2::new(f64::NEG_INFINITY, 5.0),
+        ]
+        .into_iter()
+        .map(OrderedVector2)
+        .collect();
+        let sorted: Vec<Vector2> = set.into_iter().map(OrderedVector2::into_inner).collect();
+        assert_eq!(sorted[0], Vector2::new(f64::NEG_INFINITY, 5.0));
+        assert_eq!(sorted[1], Vector2::new(1.0, -1.0));
+        assert_eq!(sorted[2], Vector2::new(1.0, 2.0));
+        assert_eq!(sorted[3], Vector2::new(f64::INFINITY, 0.0));
+        assert!(sorted[4].x().is_nan());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_sort_lexicographic() {
+        let mut points = [
+            Vector3::new(1.0, 2.0, 3.0),
+            Vector3::new(1.0, 2.0, -3.0),
+            Vector3::new(0.0, 5.0, 5.0),
+        ];
+        Vector3::sort_lexicographic(&mut points);
+        assert_eq!(
+            points,
+            [
+                Vector3::new(0.0, 5.0, 5.0),
+                Vector3::new(1.0, 2.0, -3.0),
+                Vector3::new(1.0, 2.0, 3.0),
+            ]
+        );
+
+        let mut points = [Vector2::new(2.0, 0.0), Vector2::new(1.0, 1.0)];
+        Vector2::sort_lexicographic(&mut points);
+        assert_eq!(points, [Vector2::new(1.0, 1.0), Vector2::new(2.0, 0.0)]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_sort_by_axis() {
+        let mut points = [
+            Vector3::new(1.0, 2.0, 3.0),
+            Vector3::new(2.0, 1.0, 2.0),
+            Vector3::new(3.0, 3.0, 1.0),
+        ];
+        Vector3::sort_by_axis(&mut points, 2);
+        assert_eq!(points[0], Vector3::new(3.0, 3.0, 1.0));
+        assert_eq!(points[2], Vector3::new(1.0, 2.0, 3.0));
+
+        let mut points = [Vector2::new(1.0, 2.0), Vector2::new(2.0, 1.0)];
+        Vector2::sort_by_axis(&mut points, 1);
+        assert_eq!(points, [Vector2::new(2.0, 1.0), Vector2::new(1.0, 2.0)]);
+    }
+
+    #[test]
+    fn test_sort_unstable() {
+        let mut points = [
+            Vector3::new(1.0, 2.0, 3.0),
+            Vector3::new(f64::NAN, 0.0, 0.0),
+            Vector3::new(-0.0, 5.0, 5.0),
+            Vector3::new(1.0, 2.0, -3.0),
+        ];
+        Vector3::sort_lexicographic_unstable(&mut points);
+        assert_eq!(points[0], Vector3::new(0.0, 5.0, 5.0));
+        assert_eq!(points[1], Vector3::new(1.0, 2.0, -3.0));
+        assert_eq!(points[2], Vector3::new(1.0, 2.0, 3.0));
+        assert!(points[3].x().is_nan());
+
+        Vector3::sort_by_axis_unstable(&mut points, 2);
+        assert_eq!(points[0].z(), -3.0);
+        assert_eq!(points[3].z(), 5.0);
+
+        let mut points = [Vector2::new(2.0, 0.0), Vector2::new(1.0, 1.0)];
+        Vector2::sort_lexicographic_unstable(&mut points);
+        assert_eq!(points, [Vector2::new(1.0, 1.0), Vector2::new(2.0, 0.0)]);
+        Vector2::sort_by_axis_unstable(&mut points, 1);
+        assert_eq!(points, [Vector2::new(2.0, 0.0), Vector2::new(1.0, 1.0)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sort_by_axis_unstable_out_of_range() {
+        Vector2::sort_by_axis_unstable(&mut [Vector2::zeros()], 2);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    #[should_panic]
+    fn test_sort_by_axis_out_of_range() {
+        Vector3::sort_by_axis(&mut [Vector3::zeros()], 3);
+    }
+}
@@ -0,0 +1,177 @@
+use crate::Vector3;
+
+/// A 24-byte storage form of [`Vector3`], without the SIMD padding lane.
+///
+/// `Vector3` takes 32 bytes, so large buffers of points are a third smaller when stored as
+/// `PackedVector3`. Load into a `Vector3` for arithmetic and store the result back.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PackedVector3 {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl PackedVector3 {
+    pub const ZERO: Self = Self::new(0.0, 0.0, 0.0);
+
+    #[inline]
+    #[must_use]
+    pub const fn new(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn load(self) -> Vector3 {
+        Vector3::new(self.x, self.y, self.z)
+    }
+
+    #[inline]
+    pub fn store(&mut self, v: Vector3) {
+        *self = Self::from(v);
+    }
+
+    /// Loads every element of `packed` into `out`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slices have different lengths.
+    pub fn load_slice(packed: &[Self], out: &mut [Vector3]) {
+        assert_eq!(packed.len(), out.len(), "slice lengths differ");
+        for (o, p) in out.iter_mut().zip(packed) {
+            *o = p.load();
+        }
+    }
+
+    /// Stores every element of `vectors` into `out`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slices have different lengths.
+    pub fn store_slice(vectors: &[Vector3], out: &mut [Self]) {
+        assert_eq!(vectors.len(), out.len(), "slice lengths differ");
+        for (o, v) in out.iter_mut().zip(vectors) {
+            o.store(*v);
+        }
+    }
+
+    /// Views the slice as its components, `[x0, y0, z0, x1, ...]`.
+    #[inline]
+    #[must_use]
+    pub fn as_flat_slice(slice: &[Self]) -> &[f64] {
+        // SAFETY: `Self` is `repr(C)` with three `f64` fields, so it has the size and alignment
+        // of `[f64; 3]` and no padding.
+        unsafe { core::slice::from_raw_parts(slice.as_ptr().cast(), slice.len() * 3) }
+    }
+
+    /// Mutable version of [`as_flat_slice`](Self::as_flat_slice).
+    #[inline]
+    #[must_use]
+    pub fn as_flat_slice_mut(slice: &mut [Self]) -> &mut [f64] {
+        // SAFETY: as in `as_flat_slice`, and every bit pattern is a valid `f64`.
+        unsafe { core::slice::from_raw_parts_mut(slice.as_mut_ptr().cast(), slice.len() * 3) }
+    }
+
+    /// Views components `[x0, y0, z0, x1, ...]` as packed vectors, or returns `None` if the
+    /// length is not a multiple of three.
+    #[inline]
+    #[must_use]
+    pub fn from_flat_slice(flat: &[f64]) -> Option<&[Self]> {
+        if !flat.len().is_multiple_of(3) {
+            return None;
+        }
+        // SAFETY: see `as_flat_slice`; `f64` and `Self` have the same alignment.
+        Some(unsafe { core::slice::from_raw_parts(flat.as_ptr().cast(), flat.len() / 3) })
+    }
+}
+
+impl From<Vector3> for PackedVector3 {
+    #[inline]
+    fn from(v: Vector3) -> Self {
+        Self::new(v.x(), v.y(), v.z())
+    }
+}
+
+impl From<PackedVector3> for Vector3 {
+    #[inline]
+    fn from(p: PackedVector3) -> Self {
+        p.load()
+    }
+}
+
+impl From<[f64; 3]> for PackedVector3 {
+    #[inline]
+    fn from([x, y, z]: [f64; 3]) -> Self {
+        Self::new(x, y, z)
+    }
+}
+
+impl From<PackedVector3> for [f64; 3] {
+    #[inline]
+    fn from(p: PackedVector3) -> Self {
+        [p.x, p.y, p.z]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layout() {
+        assert_eq!(size_of::<PackedVector3>(), 24);
+        assert_eq!(align_of::<PackedVector3>(), align_of::<f64>());
+        // Four packed vectors fit in the space of three `Vector3`s.
+        assert_eq!(size_of::<[PackedVector3; 4]>(), 3 * size_of::<Vector3>());
+    }
+
+    #[test]
+    fn test_load_and_store() {
+        let mut p = PackedVector3::new(1.0, -2.0, 3.5);
+        assert_eq!(p.load(), Vector3::new(1.0, -2.0, 3.5));
+        p.store(p.load() * f64::INFINITY);
+        assert_eq!(
+            p,
+            PackedVector3::new(f64::INFINITY, f64::NEG_INFINITY, f64::INFINITY)
+        );
+        assert_eq!(p.load().magnitude(), f64::INFINITY);
+        assert_eq!(Vector3::from(PackedVector3::from(Vector3::Z)), Vector3::Z);
+    }
+
+    #[test]
+    fn test_slice_conversions() {
+        let vectors = [Vector3::X, Vector3::new(1.0, 2.0, 3.0), Vector3::NEG_Z];
+        let mut packed = [PackedVector3::ZERO; 3];
+        PackedVector3::store_slice(&vectors, &mut packed);
+        assert_eq!(packed[1], PackedVector3::new(1.0, 2.0, 3.0));
+
+        let mut loaded = [Vector3::ZERO; 3];
+        PackedVector3::load_slice(&packed, &mut loaded);
+        assert_eq!(loaded, vectors);
+    }
+
+    #[test]
+    fn test_flat_slices() {
+        let mut packed = [
+            PackedVector3::new(1.0, 2.0, 3.0),
+            PackedVector3::new(4.0, 5.0, 6.0),
+        ];
+        assert_eq!(
+            PackedVector3::as_flat_slice(&packed),
+            &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]
+        );
+        PackedVector3::as_flat_slice_mut(&mut packed)[4] = -5.0;
+        assert_eq!(packed[1].y, -5.0);
+
+        let flat = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        assert_eq!(PackedVector3::from_flat_slice(&flat).unwrap()[1].z, 6.0);
+        assert!(PackedVector3::from_flat_slice(&flat[..4]).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "slice lengths differ")]
+    fn test_slice_length_mismatch() {
+        PackedVector3::load_slice(&[PackedVector3::ZERO], &mut []);
+    }
+}
@@ -0,0 +1,476 @@
+use crate::backend::{Select, SimdPartialEq, f64x4, sqrt_lanes};
+use crate::{Vector2, Vector3};
+
+/// Four `Vector3`s stored component-wise (one SIMD register per axis), so every operation
+/// works on all four vectors at once with no idle lanes or horizontal sums. Per-lane values
+/// go in and out as `[f64; 4]`, with lane `i` belonging to vector `i`.
+///
+/// Each lane gives the same bits as the corresponding `Vector3` operation, except that a dot
+/// product of exactly zero may come out as `-0.0` where `Vector3::dot` gives `0.0`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Vector3x4 {
+    pub(crate) x: f64x4,
+    pub(crate) y: f64x4,
+    pub(crate) z: f64x4,
+}
+
+/// Four `Vector2`s stored component-wise (one SIMD register per axis).
+///
+/// Each lane gives the same bits as the corresponding `Vector2` operation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Vector2x4 {
+    pub(crate) x: f64x4,
+    pub(crate) y: f64x4,
+}
+
+macro_rules! impl_packet_ops {
+    ($packet:ident, $($axis:ident),+) => {
+        impl $packet {
+            pub const LANES: usize = 4;
+
+            /// Builds a packet from the per-lane values of each component.
+            #[inline]
+            #[must_use]
+            pub const fn new($($axis: [f64; 4]),+) -> Self {
+                Self { $($axis: f64x4::from_array($axis)),+ }
+            }
+
+            $(
+                #[inline]
+                #[must_use]
+                pub fn $axis(self) -> [f64; 4] {
+                    self.$axis.to_array()
+                }
+            )+
+
+            #[inline]
+            #[must_use]
+            pub fn dot(self, rhs: Self) -> [f64; 4] {
+                self.dot_lanes(rhs).to_array()
+            }
+
+            #[inline]
+            #[must_use]
+            pub fn magnitude_squared(self) -> [f64; 4] {
+                self.dot(self)
+            }
+
+            #[inline]
+            #[must_use]
+            pub fn magnitude(self) -> [f64; 4] {
+                self.magnitude_lanes().to_array()
+            }
+
+            /// Normalizes every lane. Zero-length lanes become zero, as in `normalize`.
+            #[inline]
+            #[must_use]
+            pub fn normalize(self) -> Self {
+                let magnitude = self.magnitude_lanes();
+                let zero_length = magnitude.simd_eq(f64x4::splat(0.0));
+                let scaled = self.mul_lanes(f64x4::splat(1.0) / magnitude);
+                Self { $($axis: zero_length.select(f64x4::splat(0.0), scaled.$axis)),+ }
+            }
+
+            #[inline]
+            #[must_use]
+            pub fn distance(self, rhs: Self) -> [f64; 4] {
+                (self - rhs).magnitude()
+            }
+
+            #[inline]
+            #[must_use]
+            pub fn distance_squared(self, rhs: Self) -> [f64; 4] {
+                (self - rhs).magnitude_squared()
+            }
+
+            #[inline]
+            fn dot_lanes(self, rhs: Self) -> f64x4 {
+                // Starting from -0.0 leaves the first product unchanged, like `reduce_sum`.
+                let mut sum = f64x4::splat(-0.0);
+                $(sum += self.$axis * rhs.$axis;)+
+                sum
+            }
+
+            #[inline]
+            fn magnitude_lanes(self) -> f64x4 {
+                sqrt_lanes(self.dot_lanes(self))
+            }
+
+            #[inline]
+            fn mul_lanes(self, factor: f64x4) -> Self {
+                Self { $($axis: self.$axis * factor),+ }
+            }
+        }
+
+        impl core::ops::Add for $packet {
+            type Output = $packet;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self::Output {
+                Self { $($axis: self.$axis + rhs.$axis),+ }
+            }
+        }
+
+        impl core::ops::Sub for $packet {
+            type Output = $packet;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self { $($axis: self.$axis - rhs.$axis),+ }
+            }
+        }
+
+        impl core::ops::Mul<[f64; 4]> for $packet {
+            type Output = $packet;
+
+            /// Scales each vector by its own lane of `rhs`.
+            #[inline]
+            fn mul(self, rhs: [f64; 4]) -> Self::Output {
+                self.mul_lanes(f64x4::from_array(rhs))
+            }
+        }
+
+        impl core::ops::Mul<f64> for $packet {
+            type Output = $packet;
+
+            #[inline]
+            fn mul(self, rhs: f64) -> Self::Output {
+                self.mul_lanes(f64x4::splat(rhs))
+            }
+        }
+
+        impl core::ops::Div<[f64; 4]> for $packet {
+            type Output = $packet;
+
+            /// Divides each vector by its own lane of `rhs`.
+            #[inline]
+            fn div(self, rhs: [f64; 4]) -> Self::Output {
+                let rhs = f64x4::from_array(rhs);
+                Self { $($axis: self.$axis / rhs),+ }
+            }
+        }
+
+        impl core::ops::Neg for $packet {
+            type Output = $packet;
+
+            #[inline]
+            fn neg(self) -> Self::Output {
+                Self { $($axis: -self.$axis),+ }
+            }
+        }
+
+        impl core::ops::AddAssign for $packet {
+            #[inline]
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl core::ops::SubAssign for $packet {
+            #[inline]
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl core::ops::MulAssign<f64> for $packet {
+            #[inline]
+            fn mul_assign(&mut self, rhs: f64) {
+                *self = *self * rhs;
+            }
+        }
+    };
+}
+
+/// Slice loading and storing, with partial variants for the tail of a slice whose length is
+/// not a multiple of four.
+macro_rules! impl_packet_memory {
+    ($packet:ident, $vector:ident, $($axis:ident),+) => {
+        impl $packet {
+            #[inline]
+            #[must_use]
+            pub fn splat(v: $vector) -> Self {
+                Self { $($axis: f64x4::splat(v.$axis())),+ }
+            }
+
+            #[inline]
+            #[must_use]
+            pub fn from_array(vectors: [$vector; 4]) -> Self {
+                Self { $($axis: f64x4::from_array(vectors.map(|v| v.$axis()))),+ }
+            }
+
+            #[inline]
+            #[must_use]
+            pub fn to_array(self) -> [$vector; 4] {
+                let ($($axis),+) = ($(self.$axis.to_array()),+);
+                core::array::from_fn(|i| $vector::new($($axis[i]),+))
+            }
+
+            /// Returns the vector in `lane`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `lane` is greater than 3.
+            #[inline]
+            #[must_use]
+            pub fn extract(self, lane: usize) -> $vector {
+                $vector::new($(self.$axis[lane]),+)
+            }
+
+            /// Loads the first four vectors of `slice`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `slice` has fewer than four elements.
+            #[inline]
+            #[must_use]
+            pub fn from_slice(slice: &[$vector]) -> Self {
+                Self::from_array(core::array::from_fn(|i| slice[i]))
+            }
+
+            /// Loads up to four vectors from the start of `slice`, filling missing lanes with
+            /// `fill`. Returns the packet and the number of lanes loaded.
+            #[inline]
+            #[must_use]
+            pub fn from_slice_or(slice: &[$vector], fill: $vector) -> (Self, usize) {
+                let loaded = slice.len().min(4);
+                let vectors = core::array::from_fn(|i| slice.get(i).copied().unwrap_or(fill));
+                (Self::from_array(vectors), loaded)
+            }
+
+            /// Writes the four vectors to the start of `slice`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `slice` has fewer than four elements.
+            #[inline]
+            pub fn write_to_slice(self, slice: &mut [$vector]) {
+                slice[..4].copy_from_slice(&self.to_array());
+            }
+
+            /// Writes the first `min(4, slice.len())` lanes to `slice` and ignores the rest.
+            #[inline]
+            pub fn write_to_slice_partial(self, slice: &mut [$vector]) {
+                for (out, v) in slice.iter_mut().zip(self.to_array()) {
+                    *out = v;
+                }
+            }
+
+            /// Loads `slice[indices[i]]` into lane `i`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if an index is out of bounds.
+            #[inline]
+            #[must_use]
+            pub fn gather(slice: &[$vector], indices: [usize; 4]) -> Self {
+                Self::from_array(indices.map(|i| slice[i]))
+            }
+
+            /// Writes lane `i` to `slice[indices[i]]`, in lane order, so later lanes win when
+            /// indices repeat.
+            ///
+            /// # Panics
+            ///
+            /// Panics if an index is out of bounds.
+            #[inline]
+            pub fn scatter(self, slice: &mut [$vector], indices: [usize; 4]) {
+                for (index, v) in indices.into_iter().zip(self.to_array()) {
+                    slice[index] = v;
+                }
+            }
+        }
+    };
+}
+
+impl_packet_ops!(Vector3x4, x, y, z);
+impl_packet_ops!(Vector2x4, x, y);
+impl_packet_memory!(Vector3x4, Vector3, x, y, z);
+impl_packet_memory!(Vector2x4, Vector2, x, y);
+
+impl Vector3x4 {
+    #[inline]
+    #[must_use]
+    pub fn cross(self, rhs: Self) -> Self {
+        Self {
+            x: self.y * rhs.z - self.z * rhs.y,
+            y: self.z * rhs.x - self.x * rhs.z,
+            z: self.x * rhs.y - self.y * rhs.x,
+        }
+    }
+}
+
+impl Vector2x4 {
+    /// Returns the z component of the 3D cross product of each lane, as `Vector2::cross`.
+    #[inline]
+    #[must_use]
+    pub fn cross(self, rhs: Self) -> [f64; 4] {
+        (self.x * rhs.y - self.y * rhs.x).to_array()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vectors3() -> [Vector3; 4] {
+        [
+            Vector3::new(1.0, 2.0, 3.0),
+            Vector3::new(-0.5, 4.0, 1e-3),
+            Vector3::new(-0.0, -1e-200, 0.0),
+            Vector3::new(1e200, -1e200, 7.0),
+        ]
+    }
+
+    fn others3() -> [Vector3; 4] {
+        [
+            Vector3::new(0.3, -2.0, 5.0),
+            Vector3::X,
+            Vector3::new(2.0, 2.0, 2.0),
+            Vector3::new(-1.0, 1e-300, 3.0),
+        ]
+    }
+
+    fn vectors2() -> [Vector2; 4] {
+        [
+            Vector2::new(1.0, 2.0),
+            Vector2::new(-0.5, 1e-3),
+            Vector2::new(-0.0, -1e-200),
+            Vector2::new(3.0, -4.0),
+        ]
+    }
+
+    fn others2() -> [Vector2; 4] {
+        [
+            Vector2::new(0.3, -2.0),
+            Vector2::X,
+            Vector2::new(2.0, 2.0),
+            Vector2::new(-1.0, 1e-300),
+        ]
+    }
+
+    fn bits3(vectors: [Vector3; 4]) -> [[u64; 3]; 4] {
+        vectors.map(|v| v.to_array().map(f64::to_bits))
+    }
+
+    fn bits2(vectors: [Vector2; 4]) -> [[u64; 2]; 4] {
+        vectors.map(|v| v.to_array().map(f64::to_bits))
+    }
+
+    fn assert_lanes_bits(packet: [f64; 4], expected: [f64; 4]) {
+        let actual = packet.map(f64::to_bits);
+        assert_eq!(
+            actual,
+            expected.map(f64::to_bits),
+            "{packet:?} vs {expected:?}"
+        );
+    }
+
+    #[test]
+    fn test_vector3x4_matches_vector3() {
+        let (a, b) = (vectors3(), others3());
+        let (pa, pb) = (Vector3x4::from_array(a), Vector3x4::from_array(b));
+
+        let sums = core::array::from_fn(|i| a[i] + b[i]);
+        assert_eq!(bits3((pa + pb).to_array()), bits3(sums));
+        let differences = core::array::from_fn(|i| a[i] - b[i]);
+        assert_eq!(bits3((pa - pb).to_array()), bits3(differences));
+        assert_eq!(bits3((pa * 2.5).to_array()), bits3(a.map(|v| v * 2.5)));
+        assert_eq!(bits3((-pa).to_array()), bits3(a.map(|v| -v)));
+        let crosses = core::array::from_fn(|i| a[i].cross(b[i]));
+        assert_eq!(bits3(pa.cross(pb).to_array()), bits3(crosses));
+        let normalized = a.map(Vector3::normalize);
+        assert_eq!(bits3(pa.normalize().to_array()), bits3(normalized));
+        assert_lanes_bits(pa.dot(pb), core::array::from_fn(|i| a[i].dot(b[i])));
+        assert_lanes_bits(pa.magnitude(), a.map(Vector3::magnitude));
+        assert_lanes_bits(
+            pa.distance(pb),
+            core::array::from_fn(|i| a[i].distance(b[i])),
+        );
+        assert_lanes_bits(
+            pa.distance_squared(pb),
+            core::array::from_fn(|i| a[i].distance_squared(b[i])),
+        );
+    }
+
+    #[test]
+    fn test_vector2x4_matches_vector2() {
+        let (a, b) = (vectors2(), others2());
+        let (pa, pb) = (Vector2x4::from_array(a), Vector2x4::from_array(b));
+
+        let sums = core::array::from_fn(|i| a[i] + b[i]);
+        assert_eq!(bits2((pa + pb).to_array()), bits2(sums));
+        assert_eq!(bits2((pa * -3.0).to_array()), bits2(a.map(|v| v * -3.0)));
+        let normalized = a.map(Vector2::normalize);
+        assert_eq!(bits2(pa.normalize().to_array()), bits2(normalized));
+        assert_lanes_bits(pa.cross(pb), core::array::from_fn(|i| a[i].cross(b[i])));
+        assert_lanes_bits(pa.dot(pb), core::array::from_fn(|i| a[i].dot(b[i])));
+        assert_lanes_bits(pa.magnitude(), a.map(Vector2::magnitude));
+        assert_lanes_bits(
+            pa.distance(pb),
+            core::array::from_fn(|i| a[i].distance(b[i])),
+        );
+    }
+
+    #[test]
+    fn test_per_lane_scaling() {
+        let packet = Vector3x4::splat(Vector3::ONE) * [1.0, 2.0, 3.0, 4.0];
+        assert_eq!(packet.extract(2), Vector3::new(3.0, 3.0, 3.0));
+        let packet = packet / [1.0, 2.0, 3.0, 4.0];
+        assert_eq!(packet, Vector3x4::splat(Vector3::ONE));
+    }
+
+    #[test]
+    fn test_lane_arrays() {
+        const PACKET: Vector3x4 =
+            Vector3x4::new([1.0, 2.0, 3.0, 4.0], [0.0; 4], [-1.0, -2.0, -3.0, -4.0]);
+        assert_eq!(PACKET.extract(1), Vector3::new(2.0, 0.0, -2.0));
+        assert_eq!(PACKET.x(), [1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(PACKET.y(), [0.0; 4]);
+        assert_eq!(PACKET.z(), [-1.0, -2.0, -3.0, -4.0]);
+
+        let packet = Vector2x4::from_array(vectors2());
+        assert_eq!(Vector2x4::new(packet.x(), packet.y()), packet);
+    }
+
+    #[test]
+    fn test_slices_with_tail() {
+        let source: Vec<Vector3> = (0..6).map(|i| Vector3::new(i as f64, 1.0, -1.0)).collect();
+        let mut out = vec![Vector3::NAN; 6];
+        for (chunk, out) in source.chunks(4).zip(out.chunks_mut(4)) {
+            let (packet, loaded) = Vector3x4::from_slice_or(chunk, Vector3::ZERO);
+            assert_eq!(loaded, chunk.len());
+            (packet * 2.0).write_to_slice_partial(out);
+        }
+        assert_eq!(out, source.iter().map(|&v| v * 2.0).collect::<Vec<_>>());
+
+        let (packet, loaded) = Vector3x4::from_slice_or(&source[4..], Vector3::ONE);
+        assert_eq!(loaded, 2);
+        assert_eq!(packet.extract(3), Vector3::ONE);
+
+        let packet = Vector3x4::from_slice(&source[1..]);
+        let mut full = [Vector3::ZERO; 4];
+        packet.write_to_slice(&mut full);
+        assert_eq!(full, [source[1], source[2], source[3], source[4]]);
+    }
+
+    #[test]
+    fn test_gather_scatter() {
+        let source = vectors2();
+        let packet = Vector2x4::gather(&source, [3, 0, 0, 2]);
+        assert_eq!(
+            packet.to_array(),
+            [source[3], source[0], source[0], source[2]]
+        );
+
+        let mut out = [Vector2::NAN; 4];
+        Vector2x4::from_array(source).scatter(&mut out, [3, 2, 1, 0]);
+        assert_eq!(out, [source[3], source[2], source[1], source[0]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_slice_too_short() {
+        let _ = Vector3x4::from_slice(&[Vector3::ZERO; 3]);
+    }
+}
@@ -0,0 +1,165 @@
+//! Parallel versions of the slice operations, built on rayon.
+//!
+//! Slices are split into fixed blocks of [`CHUNK_LEN`] vectors, each block is processed with
+//! the sequential [`batch`](crate::batch) or [`kernels`](crate::kernels) function, and
+//! per-block results are combined in block order. The reductions therefore give the same bits
+//! for any number of threads, though [`sum`] can differ in rounding from
+//! [`batch::sum`](crate::batch::sum) over the whole slice.
+
+use crate::batch::{self, BatchVector};
+use crate::{Matrix4, Vector3, kernels};
+use rayon::prelude::*;
+use std::vec::Vec;
+
+/// The number of vectors each parallel task processes.
+pub const CHUNK_LEN: usize = 4096;
+
+/// Transforms every point with [`Matrix4::transform_point`].
+pub fn transform(matrix: &Matrix4, points: &mut [Vector3]) {
+    points
+        .par_chunks_mut(CHUNK_LEN)
+        .for_each(|chunk| kernels::transform(matrix, chunk));
+}
+
+/// Normalizes every vector. Zero vectors stay zero, as with `normalize`.
+pub fn normalize<V: BatchVector + Send>(vectors: &mut [V]) {
+    vectors
+        .par_chunks_mut(CHUNK_LEN)
+        .for_each(batch::normalize_all);
+}
+
+/// Returns the sum of the vectors, or zero for an empty slice.
+#[must_use]
+pub fn sum<V: BatchVector + Send + Sync>(vectors: &[V]) -> V {
+    let partials: Vec<V> = vectors.par_chunks(CHUNK_LEN).map(batch::sum).collect();
+    batch::sum(&partials)
+}
+
+/// Returns the component-wise minimum and maximum, or `None` for an empty slice. NaN
+/// components are ignored unless every value of that component is NaN.
+#[must_use]
+pub fn bounds<V: BatchVector + Send + Sync>(vectors: &[V]) -> Option<(V, V)> {
+    let partials: Vec<(V, V)> = vectors
+        .par_chunks(CHUNK_LEN)
+        .filter_map(batch::min_max_bounds)
+        .collect();
+    partials
+        .into_iter()
+        .reduce(|(min_a, max_a), (min_b, max_b)| (min_a.min(min_b), max_a.max(max_b)))
+}
+
+/// Returns the index of the vector closest to `point` and its distance, or `None` if the slice
+/// is empty or every distance is NaN. Ties go to the lowest index.
+#[must_use]
+pub fn nearest_to<V: BatchVector + Send + Sync>(vectors: &[V], point: V) -> Option<(usize, f64)> {
+    let partials: Vec<(usize, f64)> = vectors
+        .par_chunks(CHUNK_LEN)
+        .enumerate()
+        .filter_map(|(chunk, vectors)| {
+            let (index, distance_squared) = nearest_squared(vectors, point)?;
+            Some((chunk * CHUNK_LEN + index, distance_squared))
+        })
+        .collect();
+    let (index, distance_squared) = closer(partials)?;
+    Some((index, crate::math::sqrt(distance_squared)))
+}
+
+/// Sequential nearest search by squared distance.
+fn nearest_squared<V: BatchVector>(vectors: &[V], point: V) -> Option<(usize, f64)> {
+    closer(
+        vectors
+            .iter()
+            .map(|v| v.distance_squared(point))
+            .enumerate(),
+    )
+}
+
+/// Returns the candidate with the smallest distance, keeping the first on ties and skipping NaN.
+fn closer(candidates: impl IntoIterator<Item = (usize, f64)>) -> Option<(usize, f64)> {
+    candidates
+        .into_iter()
+        .filter(|(_, distance)| !distance.is_nan())
+        .reduce(|best, candidate| {
+            if candidate.1 < best.1 {
+                candidate
+            } else {
+                best
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Vector2;
+    use crate::test_util::sample_vectors;
+
+    fn with_threads<T: Send>(threads: usize, f: impl FnOnce() -> T + Send) -> T {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .unwrap()
+            .install(f)
+    }
+
+    #[test]
+    fn test_results_independent_of_thread_count() {
+        // Several blocks plus a partial one.
+        let vectors = sample_vectors(3 * CHUNK_LEN + 123, 0.0);
+        let point = Vector3::new(0.1, 0.2, 0.3);
+        let run = || (sum(&vectors), bounds(&vectors), nearest_to(&vectors, point));
+
+        let (sum1, bounds1, nearest1) = with_threads(1, run);
+        for threads in [2, 3, 8] {
+            let (sum_n, bounds_n, nearest_n) = with_threads(threads, run);
+            assert_eq!(
+                sum_n.to_array().map(f64::to_bits),
+                sum1.to_array().map(f64::to_bits)
+            );
+            assert_eq!(bounds_n, bounds1);
+            assert_eq!(nearest_n, nearest1);
+        }
+    }
+
+    #[test]
+    fn test_matches_sequential() {
+        let vectors = sample_vectors(2 * CHUNK_LEN + 7, 0.0);
+        let expected: Vector3 = vectors.iter().sum();
+        assert!(sum(&vectors).abs_diff_eq(expected, 1e-9));
+        assert_eq!(bounds(&vectors), batch::min_max_bounds(&vectors));
+
+        let point = vectors[CHUNK_LEN + 5] + Vector3::new(1e-6, 0.0, 0.0);
+        let (index, distance) = nearest_to(&vectors, point).unwrap();
+        assert_eq!(index, CHUNK_LEN + 5);
+        assert!((distance - 1e-6).abs() < 1e-12);
+
+        let matrix =
+            Matrix4::look_at(Vector3::new(1.0, 2.0, 3.0), Vector3::ZERO, Vector3::Y).unwrap();
+        let mut transformed = vectors.clone();
+        transform(&matrix, &mut transformed);
+        let mut normalized = vectors.clone();
+        normalize(&mut normalized);
+        for i in 0..vectors.len() {
+            assert_eq!(transformed[i], matrix.transform_point(vectors[i]));
+            assert_eq!(normalized[i], vectors[i].normalize());
+        }
+    }
+
+    #[test]
+    fn test_nearest_ties_and_nan() {
+        let mut vectors = vec![Vector2::new(5.0, 0.0); CHUNK_LEN + 2];
+        vectors[0] = Vector2::NAN;
+        vectors[CHUNK_LEN] = Vector2::X;
+        vectors[CHUNK_LEN + 1] = Vector2::NEG_X;
+        assert_eq!(nearest_to(&vectors, Vector2::ZERO), Some((CHUNK_LEN, 1.0)));
+        assert_eq!(nearest_to(&[Vector2::NAN], Vector2::ZERO), None);
+        assert_eq!(nearest_to::<Vector2>(&[], Vector2::ZERO), None);
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(sum::<Vector3>(&[]), Vector3::ZERO);
+        assert_eq!(bounds::<Vector3>(&[]), None);
+        normalize::<Vector3>(&mut []);
+    }
+}
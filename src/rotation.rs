@@ -0,0 +1,196 @@
+use crate::Vector3;
+use crate::math;
+
+/// A 3D rotation stored as an orthonormal 3x3 matrix with `Vector3` columns. Rotations
+/// follow the right-hand rule and act on column vectors, so `(a * b).rotate(v)` applies `b`
+/// first.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rotation3 {
+    cols: [Vector3; 3],
+}
+
+impl Rotation3 {
+    pub const IDENTITY: Self = Self {
+        cols: [Vector3::X, Vector3::Y, Vector3::Z],
+    };
+
+    /// Builds a rotation from the images of the x, y and z axes. The columns must be
+    /// orthonormal and right-handed.
+    #[inline]
+    #[must_use]
+    pub const fn from_cols(x_axis: Vector3, y_axis: Vector3, z_axis: Vector3) -> Self {
+        Self {
+            cols: [x_axis, y_axis, z_axis],
+        }
+    }
+
+    /// Rotation of `angle` radians about `axis`, which must be normalized.
+    #[must_use]
+    pub fn from_axis_angle(axis: Vector3, angle: f64) -> Self {
+        let (sin, cos) = math::sin_cos(angle);
+        let (x, y, z) = (axis.x(), axis.y(), axis.z());
+        let t = 1.0 - cos;
+        Self::from_cols(
+            Vector3::new(t * x * x + cos, t * x * y + sin * z, t * x * z - sin * y),
+            Vector3::new(t * x * y - sin * z, t * y * y + cos, t * y * z + sin * x),
+            Vector3::new(t * x * z + sin * y, t * y * z - sin * x, t * z * z + cos),
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn from_rotation_x(angle: f64) -> Self {
+        let (sin, cos) = math::sin_cos(angle);
+        Self::from_cols(
+            Vector3::X,
+            Vector3::new(0.0, cos, sin),
+            Vector3::new(0.0, -sin, cos),
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn from_rotation_y(angle: f64) -> Self {
+        let (sin, cos) = math::sin_cos(angle);
+        Self::from_cols(
+            Vector3::new(cos, 0.0, -sin),
+            Vector3::Y,
+            Vector3::new(sin, 0.0, cos),
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn from_rotation_z(angle: f64) -> Self {
+        let (sin, cos) = math::sin_cos(angle);
+        Self::from_cols(
+            Vector3::new(cos, sin, 0.0),
+            Vector3::new(-sin, cos, 0.0),
+            Vector3::Z,
+        )
+    }
+
+    /// Returns column `index` (0 for the image of the x axis).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 2.
+    #[inline]
+    #[must_use]
+    pub fn col(&self, index: usize) -> Vector3 {
+        self.cols[index]
+    }
+
+    /// Returns row `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 2.
+    #[inline]
+    #[must_use]
+    pub fn row(&self, index: usize) -> Vector3 {
+        Vector3::new(
+            self.cols[0][index],
+            self.cols[1][index],
+            self.cols[2][index],
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn rotate(&self, v: Vector3) -> Vector3 {
+        self.cols[0] * v.x() + self.cols[1] * v.y() + self.cols[2] * v.z()
+    }
+
+    /// Returns the inverse rotation, which for a rotation matrix is its transpose.
+    #[inline]
+    #[must_use]
+    pub fn inverse(&self) -> Self {
+        Self::from_cols(self.row(0), self.row(1), self.row(2))
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn abs_diff_eq(&self, rhs: &Self, epsilon: f64) -> bool {
+        (0..3).all(|i| self.cols[i].abs_diff_eq(rhs.cols[i], epsilon))
+    }
+}
+
+impl Default for Rotation3 {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl core::ops::Mul for Rotation3 {
+    type Output = Rotation3;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::from_cols(
+            self.rotate(rhs.cols[0]),
+            self.rotate(rhs.cols[1]),
+            self.rotate(rhs.cols[2]),
+        )
+    }
+}
+
+impl core::ops::Mul<Vector3> for Rotation3 {
+    type Output = Vector3;
+
+    #[inline]
+    fn mul(self, rhs: Vector3) -> Self::Output {
+        self.rotate(rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_vec_approx_eq;
+    use core::f64::consts::FRAC_PI_2;
+
+    #[test]
+    fn test_axis_rotations() {
+        assert_vec_approx_eq!(
+            Rotation3::from_rotation_x(FRAC_PI_2) * Vector3::Y,
+            Vector3::Z
+        );
+        assert_vec_approx_eq!(
+            Rotation3::from_rotation_y(FRAC_PI_2) * Vector3::Z,
+            Vector3::X
+        );
+        assert_vec_approx_eq!(
+            Rotation3::from_rotation_z(FRAC_PI_2) * Vector3::X,
+            Vector3::Y
+        );
+    }
+
+    #[test]
+    fn test_from_axis_angle_matches_axis_rotations() {
+        for angle in [-2.0, 0.3, 1.0, 3.0] {
+            assert!(
+                Rotation3::from_axis_angle(Vector3::X, angle)
+                    .abs_diff_eq(&Rotation3::from_rotation_x(angle), 1e-15)
+            );
+            assert!(
+                Rotation3::from_axis_angle(Vector3::Y, angle)
+                    .abs_diff_eq(&Rotation3::from_rotation_y(angle), 1e-15)
+            );
+            assert!(
+                Rotation3::from_axis_angle(Vector3::Z, angle)
+                    .abs_diff_eq(&Rotation3::from_rotation_z(angle), 1e-15)
+            );
+        }
+    }
+
+    #[test]
+    fn test_compose_and_inverse() {
+        let a = Rotation3::from_axis_angle(Vector3::new(1.0, 2.0, 3.0).normalize(), 0.7);
+        let b = Rotation3::from_rotation_y(-1.2);
+        let v = Vector3::new(0.5, -1.0, 2.0);
+        assert_vec_approx_eq!((a * b) * v, a * (b * v));
+        assert!((a * a.inverse()).abs_diff_eq(&Rotation3::IDENTITY, 1e-15));
+        assert_eq!(a.row(1)[2], a.col(2)[1]);
+    }
+}
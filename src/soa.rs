@@ -0,0 +1,501 @@
+use crate::Vector3;
+use crate::backend::{SimdFloat, f64x4};
+use std::vec::Vec;
+
+/// A growable collection of `Vector3`s stored as separate x, y and z arrays.
+///
+/// Each component array is a `Vec<f64x4>`, so the bulk operations process four vectors per
+/// instruction, and without `scalar-math` the arrays are aligned for 256-bit loads. Lanes past
+/// `len()` in the last chunk are kept at zero. Single elements go in and out as `Vector3`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Vector3Soa {
+    x: Vec<f64x4>,
+    y: Vec<f64x4>,
+    z: Vec<f64x4>,
+    len: usize,
+}
+
+const LANES: usize = 4;
+
+#[inline]
+fn chunks_for(len: usize) -> usize {
+    len.div_ceil(LANES)
+}
+
+// `as_lanes` relies on this for both backends.
+const _: () = assert!(size_of::<f64x4>() == size_of::<[f64; LANES]>());
+
+/// Views chunks as their lanes, truncated to `len`.
+#[inline]
+fn as_lanes(chunks: &[f64x4], len: usize) -> &[f64] {
+    // SAFETY: `f64x4` is either `core::simd::Simd<f64, 4>`, which has the layout of
+    // `[f64; 4]` (possibly with greater alignment), or the `#[repr(transparent)]` wrapper around
+    // `[f64; 4]` from `scalar-math`. Either way a slice of them is `chunks.len() * 4` contiguous,
+    // initialized `f64`s, and `len` never exceeds that.
+    unsafe { core::slice::from_raw_parts(chunks.as_ptr().cast(), len) }
+}
+
+impl Vector3Soa {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty collection with room for at least `capacity` vectors.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        let chunks = chunks_for(capacity);
+        Self {
+            x: Vec::with_capacity(chunks),
+            y: Vec::with_capacity(chunks),
+            z: Vec::with_capacity(chunks),
+            len: 0,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn clear(&mut self) {
+        self.x.clear();
+        self.y.clear();
+        self.z.clear();
+        self.len = 0;
+    }
+
+    pub fn push(&mut self, v: Vector3) {
+        let (chunk, lane) = (self.len / LANES, self.len % LANES);
+        if lane == 0 {
+            self.x.push(f64x4::splat(0.0));
+            self.y.push(f64x4::splat(0.0));
+            self.z.push(f64x4::splat(0.0));
+        }
+        self.x[chunk][lane] = v.x();
+        self.y[chunk][lane] = v.y();
+        self.z[chunk][lane] = v.z();
+        self.len += 1;
+    }
+
+    pub fn pop(&mut self) -> Option<Vector3> {
+        let index = self.len.checked_sub(1)?;
+        let v = self.load(index);
+        let (chunk, lane) = (index / LANES, index % LANES);
+        if lane == 0 {
+            self.x.pop();
+            self.y.pop();
+            self.z.pop();
+        } else {
+            self.x[chunk][lane] = 0.0;
+            self.y[chunk][lane] = 0.0;
+            self.z[chunk][lane] = 0.0;
+        }
+        self.len = index;
+        Some(v)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<Vector3> {
+        (index < self.len).then(|| self.load(index))
+    }
+
+    /// Replaces the vector at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub fn set(&mut self, index: usize, v: Vector3) {
+        assert!(
+            index < self.len,
+            "index {index} out of bounds for length {}",
+            self.len
+        );
+        let (chunk, lane) = (index / LANES, index % LANES);
+        self.x[chunk][lane] = v.x();
+        self.y[chunk][lane] = v.y();
+        self.z[chunk][lane] = v.z();
+    }
+
+    /// Returns the x components.
+    #[inline]
+    #[must_use]
+    pub fn xs(&self) -> &[f64] {
+        as_lanes(&self.x, self.len)
+    }
+
+    /// Returns the y components.
+    #[inline]
+    #[must_use]
+    pub fn ys(&self) -> &[f64] {
+        as_lanes(&self.y, self.len)
+    }
+
+    /// Returns the z components.
+    #[inline]
+    #[must_use]
+    pub fn zs(&self) -> &[f64] {
+        as_lanes(&self.z, self.len)
+    }
+
+    #[inline]
+    pub fn iter(&self) -> Vector3SoaIter<'_> {
+        Vector3SoaIter {
+            soa: self,
+            front: 0,
+            back: self.len,
+        }
+    }
+
+    #[must_use]
+    pub fn to_vec(&self) -> Vec<Vector3> {
+        self.iter().collect()
+    }
+
+    /// Adds `offset` to every vector.
+    pub fn translate(&mut self, offset: Vector3) {
+        let (ox, oy, oz) = splat(offset);
+        for ((x, y), z) in self.x.iter_mut().zip(&mut self.y).zip(&mut self.z) {
+            *x += ox;
+            *y += oy;
+            *z += oz;
+        }
+        self.clear_tail();
+    }
+
+    /// Multiplies every vector by `factor`.
+    pub fn scale(&mut self, factor: f64) {
+        let factor = f64x4::splat(factor);
+        for ((x, y), z) in self.x.iter_mut().zip(&mut self.y).zip(&mut self.z) {
+            *x *= factor;
+            *y *= factor;
+            *z *= factor;
+        }
+        self.clear_tail();
+    }
+
+    /// Writes the dot product of each vector with `rhs` to `out`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out.len()` differs from `len()`.
+    pub fn dot(&self, rhs: Vector3, out: &mut [f64]) {
+        assert_eq!(out.len(), self.len, "output length differs");
+        let (rx, ry, rz) = splat(rhs);
+        let chunks = self.x.iter().zip(&self.y).zip(&self.z);
+        for (out, ((x, y), z)) in out.chunks_mut(LANES).zip(chunks) {
+            let dots = *x * rx + *y * ry + *z * rz;
+            out.copy_from_slice(&dots.as_array()[..out.len()]);
+        }
+    }
+
+    /// Returns the component-wise minimum and maximum, or `None` if the collection is empty.
+    /// NaN components are ignored unless every value of that component is NaN.
+    #[must_use]
+    pub fn bounds(&self) -> Option<(Vector3, Vector3)> {
+        let first = self.get(0)?;
+        // Unused tail lanes hold zeros, which must not count, so fill them with a real element.
+        let (fx, fy, fz) = splat(first);
+        let (mut min_x, mut min_y, mut min_z) = (fx, fy, fz);
+        let (mut max_x, mut max_y, mut max_z) = (fx, fy, fz);
+        for chunk in 0..self.x.len() {
+            let (x, y, z) = if (chunk + 1) * LANES > self.len {
+                let used = self.len - chunk * LANES;
+                (
+                    fill_tail(self.x[chunk], fx, used),
+                    fill_tail(self.y[chunk], fy, used),
+                    fill_tail(self.z[chunk], fz, used),
+                )
+            } else {
+                (self.x[chunk], self.y[chunk], self.z[chunk])
+            };
+            (min_x, min_y, min_z) = (min_x.simd_min(x), min_y.simd_min(y), min_z.simd_min(z));
+            (max_x, max_y, max_z) = (max_x.simd_max(x), max_y.simd_max(y), max_z.simd_max(z));
+        }
+        let min = |lanes: f64x4| lanes.to_array().into_iter().fold(f64::NAN, f64::min);
+        let max = |lanes: f64x4| lanes.to_array().into_iter().fold(f64::NAN, f64::max);
+        Some((
+            Vector3::new(min(min_x), min(min_y), min(min_z)),
+            Vector3::new(max(max_x), max(max_y), max(max_z)),
+        ))
+    }
+
+    /// Returns the mean of the vectors, or `None` if the collection is empty. The summation
+    /// order differs from [`Vector3::centroid`], so results can differ in the last bits.
+    #[must_use]
+    pub fn centroid(&self) -> Option<Vector3> {
+        if self.is_empty() {
+            return None;
+        }
+        let sum = |chunks: &[f64x4]| {
+            chunks
+                .iter()
+                .fold(f64x4::splat(0.0), |sum, &lanes| sum + lanes)
+                .reduce_sum()
+        };
+        let n = self.len as f64;
+        Some(Vector3::new(
+            sum(&self.x) / n,
+            sum(&self.y) / n,
+            sum(&self.z) / n,
+        ))
+    }
+
+    #[inline]
+    fn load(&self, index: usize) -> Vector3 {
+        let (chunk, lane) = (index / LANES, index % LANES);
+        Vector3::new(
+            self.x[chunk][lane],
+            self.y[chunk][lane],
+            self.z[chunk][lane],
+        )
+    }
+
+    /// Resets the unused lanes of the last chunk to zero after a bulk operation.
+    fn clear_tail(&mut self) {
+        let used = self.len % LANES;
+        if used != 0 {
+            let last = self.x.len() - 1;
+            for lane in used..LANES {
+                self.x[last][lane] = 0.0;
+                self.y[last][lane] = 0.0;
+                self.z[last][lane] = 0.0;
+            }
+        }
+    }
+}
+
+/// Splats each component of `v` across four lanes.
+#[inline]
+fn splat(v: Vector3) -> (f64x4, f64x4, f64x4) {
+    (
+        f64x4::splat(v.x()),
+        f64x4::splat(v.y()),
+        f64x4::splat(v.z()),
+    )
+}
+
+/// Replaces lanes from `used` onward with the lanes of `fill`.
+#[inline]
+fn fill_tail(mut lanes: f64x4, fill: f64x4, used: usize) -> f64x4 {
+    for lane in used..LANES {
+        lanes[lane] = fill[lane];
+    }
+    lanes
+}
+
+/// Iterator over the vectors of a [`Vector3Soa`].
+#[derive(Debug, Clone)]
+pub struct Vector3SoaIter<'a> {
+    soa: &'a Vector3Soa,
+    front: usize,
+    back: usize,
+}
+
+impl Iterator for Vector3SoaIter<'_> {
+    type Item = Vector3;
+
+    #[inline]
+    fn next(&mut self) -> Option<Vector3> {
+        (self.front < self.back).then(|| {
+            self.front += 1;
+            self.soa.load(self.front - 1)
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        (remaining, Some(remaining))
+    }
+}
+
+impl DoubleEndedIterator for Vector3SoaIter<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Vector3> {
+        (self.front < self.back).then(|| {
+            self.back -= 1;
+            self.soa.load(self.back)
+        })
+    }
+}
+
+impl ExactSizeIterator for Vector3SoaIter<'_> {}
+
+impl<'a> IntoIterator for &'a Vector3Soa {
+    type Item = Vector3;
+    type IntoIter = Vector3SoaIter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Vector3SoaIter<'a> {
+        self.iter()
+    }
+}
+
+impl FromIterator<Vector3> for Vector3Soa {
+    fn from_iter<I: IntoIterator<Item = Vector3>>(iter: I) -> Self {
+        let mut soa = Self::new();
+        soa.extend(iter);
+        soa
+    }
+}
+
+impl Extend<Vector3> for Vector3Soa {
+    fn extend<I: IntoIterator<Item = Vector3>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        let additional = chunks_for(self.len + iter.size_hint().0) - self.x.len();
+        self.x.reserve(additional);
+        self.y.reserve(additional);
+        self.z.reserve(additional);
+        for v in iter {
+            self.push(v);
+        }
+    }
+}
+
+impl From<&[Vector3]> for Vector3Soa {
+    fn from(vectors: &[Vector3]) -> Self {
+        vectors.iter().copied().collect()
+    }
+}
+
+impl From<Vec<Vector3>> for Vector3Soa {
+    fn from(vectors: Vec<Vector3>) -> Self {
+        Self::from(vectors.as_slice())
+    }
+}
+
+impl From<&Vector3Soa> for Vec<Vector3> {
+    fn from(soa: &Vector3Soa) -> Self {
+        soa.to_vec()
+    }
+}
+
+impl From<Vector3Soa> for Vec<Vector3> {
+    fn from(soa: Vector3Soa) -> Self {
+        soa.to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_vec_approx_eq;
+
+    fn sample(len: usize) -> Vec<Vector3> {
+        (0..len)
+            .map(|i| {
+                let t = i as f64;
+                Vector3::new(t + 1.0, 10.0 - t * 0.5, (t * 0.7).sin() + 2.0)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_push_get_set_pop() {
+        let mut soa = Vector3Soa::new();
+        assert!(soa.is_empty());
+        for v in sample(6) {
+            soa.push(v);
+        }
+        assert_eq!(soa.len(), 6);
+        assert_eq!(soa.get(5), Some(sample(6)[5]));
+        assert_eq!(soa.get(6), None);
+
+        soa.set(4, Vector3::NEG_Z);
+        assert_eq!(soa.get(4), Some(Vector3::NEG_Z));
+        assert_eq!(soa.zs()[4], -1.0);
+        assert_eq!(soa.xs().len(), 6);
+
+        assert_eq!(soa.pop(), Some(sample(6)[5]));
+        assert_eq!(soa.pop(), Some(Vector3::NEG_Z));
+        assert_eq!(soa.len(), 4);
+        soa.push(Vector3::ONE);
+        assert_eq!(soa.to_vec().last(), Some(&Vector3::ONE));
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_set_out_of_bounds() {
+        let mut soa: Vector3Soa = sample(3).into();
+        soa.set(3, Vector3::ZERO);
+    }
+
+    #[test]
+    fn test_conversions_and_iterators() {
+        for len in [0, 1, 4, 7] {
+            let vectors = sample(len);
+            let soa = Vector3Soa::from(vectors.clone());
+            assert_eq!(soa.len(), len);
+            assert_eq!(Vec::from(&soa), vectors);
+            assert_eq!(soa.iter().len(), len);
+            assert_eq!(
+                soa.iter().rev().collect::<Vec<_>>(),
+                vectors.iter().rev().copied().collect::<Vec<_>>()
+            );
+            assert_eq!((&soa).into_iter().collect::<Vector3Soa>(), soa);
+        }
+    }
+
+    #[test]
+    fn test_translate_and_scale() {
+        let vectors = sample(7);
+        let mut soa = Vector3Soa::from(vectors.as_slice());
+        soa.translate(Vector3::new(1.0, -2.0, 0.5));
+        soa.scale(3.0);
+        for (v, original) in soa.iter().zip(&vectors) {
+            assert_eq!(v, (*original + Vector3::new(1.0, -2.0, 0.5)) * 3.0);
+        }
+        // Unused lanes stay zero, so the collection still equals a freshly built one.
+        assert_eq!(soa, soa.iter().collect::<Vector3Soa>());
+    }
+
+    #[test]
+    fn test_dot() {
+        let vectors = sample(6);
+        let soa = Vector3Soa::from(vectors.as_slice());
+        let rhs = Vector3::new(0.5, -1.0, 2.0);
+        let mut out = vec![0.0; 6];
+        soa.dot(rhs, &mut out);
+        for (d, v) in out.iter().zip(&vectors) {
+            assert!((d - v.dot(rhs)).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_bounds_ignore_unused_lanes() {
+        // All components are far from zero, so zero-filled tail lanes would show up.
+        let vectors = sample(5);
+        let soa = Vector3Soa::from(vectors.as_slice());
+        let (min, max) = soa.bounds().unwrap();
+        let expected_min = vectors.iter().fold(Vector3::INFINITY, |m, v| {
+            Vector3::new(m.x().min(v.x()), m.y().min(v.y()), m.z().min(v.z()))
+        });
+        let expected_max = vectors.iter().fold(Vector3::NEG_INFINITY, |m, v| {
+            Vector3::new(m.x().max(v.x()), m.y().max(v.y()), m.z().max(v.z()))
+        });
+        assert_eq!(min, expected_min);
+        assert_eq!(max, expected_max);
+        assert_eq!(Vector3Soa::new().bounds(), None);
+    }
+
+    #[test]
+    fn test_centroid() {
+        let vectors = sample(9);
+        let soa = Vector3Soa::from(vectors.as_slice());
+        assert_vec_approx_eq!(
+            soa.centroid().unwrap(),
+            Vector3::centroid(vectors.iter()).unwrap()
+        );
+        assert_eq!(Vector3Soa::new().centroid(), None);
+    }
+}
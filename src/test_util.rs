@@ -0,0 +1,15 @@
+//! Fixtures shared by the unit tests.
+
+use crate::Vector3;
+use std::vec::Vec;
+
+/// Returns `len` deterministic vectors with mixed signs and magnitudes. Different `seed`s give
+/// different sequences.
+pub(crate) fn sample_vectors(len: usize, seed: f64) -> Vec<Vector3> {
+    (0..len)
+        .map(|i| {
+            let t = i as f64 + seed;
+            Vector3::new(t.sin() * 3.0, t.cos() - 0.5, (t * 0.37).sin() * 10.0)
+        })
+        .collect()
+}
@@ -1,5 +1,11 @@
-use std::fmt::{Debug, Formatter, Result};
-use std::simd::f64x2;
+use crate::FastVecError;
+use crate::approx;
+use crate::backend::{SimdFloat, f64x2, simd_swizzle};
+use crate::format::{ParseVectorError, parse_components, write_components};
+use crate::math;
+use core::borrow::Borrow;
+use core::fmt::{Debug, Display, Formatter, LowerExp, Result, UpperExp};
+use core::str::FromStr;
 
 pub struct Vector2(f64x2);
 
@@ -9,35 +15,76 @@ impl Debug for Vector2 {
     }
 }
 
+/// Writes `(x, y)`. Finite values and infinities round-trip exactly through [`FromStr`]; every NaN
+/// is written as `NaN`, so its sign and payload are lost.
+impl Display for Vector2 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write_components(f, &self.to_array(), Display::fmt)
+    }
+}
+
+impl LowerExp for Vector2 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write_components(f, &self.to_array(), LowerExp::fmt)
+    }
+}
+
+impl UpperExp for Vector2 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write_components(f, &self.to_array(), UpperExp::fmt)
+    }
+}
+
+/// Parses `(x, y)` or `[x, y]`. A `NaN` component parses as the canonical positive quiet NaN.
+impl FromStr for Vector2 {
+    type Err = ParseVectorError;
+
+    fn from_str(s: &str) -> core::result::Result<Self, Self::Err> {
+        parse_components(s).map(Self::from_array)
+    }
+}
+
 impl Vector2 {
+    pub const ZERO: Self = Self::new(0.0, 0.0);
+    pub const ONE: Self = Self::new(1.0, 1.0);
+    pub const X: Self = Self::new(1.0, 0.0);
+    pub const Y: Self = Self::new(0.0, 1.0);
+    pub const NEG_X: Self = Self::new(-1.0, 0.0);
+    pub const NEG_Y: Self = Self::new(0.0, -1.0);
+    pub const NAN: Self = Self::new(f64::NAN, f64::NAN);
+    pub const INFINITY: Self = Self::new(f64::INFINITY, f64::INFINITY);
+    pub const NEG_INFINITY: Self = Self::new(f64::NEG_INFINITY, f64::NEG_INFINITY);
+    pub const MIN: Self = Self::new(f64::MIN, f64::MIN);
+    pub const MAX: Self = Self::new(f64::MAX, f64::MAX);
+
     #[inline]
     #[must_use]
-    pub fn new(x: f64, y: f64) -> Self {
+    pub const fn new(x: f64, y: f64) -> Self {
         Self(f64x2::from_array([x, y]))
     }
 
     #[inline]
     #[must_use]
-    pub fn zeros() -> Self {
-        Self(f64x2::default())
+    pub const fn zeros() -> Self {
+        Self::ZERO
     }
 
     #[inline]
     #[must_use]
-    pub fn ones() -> Self {
-        Self(f64x2::from_array([1.0, 1.0]))
+    pub const fn ones() -> Self {
+        Self::ONE
     }
 
     #[inline]
     #[must_use]
-    pub fn x(&self) -> f64 {
-        self.0[0]
+    pub const fn x(&self) -> f64 {
+        self.0.as_array()[0]
     }
 
     #[inline]
     #[must_use]
-    pub fn y(&self) -> f64 {
-        self.0[1]
+    pub const fn y(&self) -> f64 {
+        self.0.as_array()[1]
     }
 
     #[inline]
@@ -50,6 +97,86 @@ impl Vector2 {
         self.0[1] = y;
     }
 
+    #[inline]
+    #[must_use]
+    pub const fn from_array(array: [f64; 2]) -> Self {
+        Self(f64x2::from_array(array))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn to_array(self) -> [f64; 2] {
+        self.0.to_array()
+    }
+
+    /// Const-evaluable `self + rhs`. At runtime, prefer the operator.
+    #[inline]
+    #[must_use]
+    pub const fn const_add(self, rhs: Self) -> Self {
+        Self::new(self.x() + rhs.x(), self.y() + rhs.y())
+    }
+
+    /// Const-evaluable `self - rhs`. At runtime, prefer the operator.
+    #[inline]
+    #[must_use]
+    pub const fn const_sub(self, rhs: Self) -> Self {
+        Self::new(self.x() - rhs.x(), self.y() - rhs.y())
+    }
+
+    /// Const-evaluable `self * rhs`. At runtime, prefer the operator.
+    #[inline]
+    #[must_use]
+    pub const fn const_mul(self, rhs: f64) -> Self {
+        Self::new(self.x() * rhs, self.y() * rhs)
+    }
+
+    /// Const-evaluable `self / rhs`. At runtime, prefer the operator.
+    #[inline]
+    #[must_use]
+    pub const fn const_div(self, rhs: f64) -> Self {
+        Self::new(self.x() / rhs, self.y() / rhs)
+    }
+
+    /// Const-evaluable `-self`. At runtime, prefer the operator.
+    #[inline]
+    #[must_use]
+    pub const fn const_neg(self) -> Self {
+        Self::new(-self.x(), -self.y())
+    }
+
+    /// Const-evaluable [`dot`](Self::dot), bit-for-bit equal to it.
+    #[inline]
+    #[must_use]
+    pub const fn const_dot(self, rhs: Self) -> f64 {
+        self.x() * rhs.x() + self.y() * rhs.y()
+    }
+
+    /// Builds a vector from the first two elements of `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` has fewer than two elements.
+    #[inline]
+    #[must_use]
+    pub fn from_slice(slice: &[f64]) -> Self {
+        Self(f64x2::from_slice(slice))
+    }
+
+    /// Writes the components into the first two elements of `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` has fewer than two elements.
+    #[inline]
+    pub fn write_to_slice(self, slice: &mut [f64]) {
+        slice[..2].copy_from_slice(self.0.as_array());
+    }
+
+    #[inline]
+    pub fn iter(&self) -> core::array::IntoIter<f64, 2> {
+        self.to_array().into_iter()
+    }
+
     #[inline]
     #[must_use]
     pub fn dot(self, rhs: Self) -> f64 {
@@ -57,6 +184,16 @@ impl Vector2 {
         prod[0] + prod[1]
     }
 
+    /// Dot product computed by Kahan's difference of products with fused multiply-adds,
+    /// accurate to 1.5 ulps even when the two products nearly cancel. Not bit-for-bit equal to
+    /// [`dot`](Self::dot). Without hardware FMA (e.g. x86-64 builds without
+    /// `-C target-feature=+fma`) it falls back to a much slower software implementation.
+    #[inline]
+    #[must_use]
+    pub fn dot_fma(self, rhs: Self) -> f64 {
+        math::difference_of_products(self.x(), rhs.x(), -self.y(), rhs.y())
+    }
+
     #[inline]
     #[must_use]
     pub fn magnitude_squared(self) -> f64 {
@@ -66,7 +203,7 @@ impl Vector2 {
     #[inline]
     #[must_use]
     pub fn magnitude(self) -> f64 {
-        self.magnitude_squared().sqrt()
+        math::sqrt(self.magnitude_squared())
     }
 
     #[inline]
@@ -80,12 +217,220 @@ impl Vector2 {
         }
     }
 
+    /// Approximates `1 / magnitude()` from the CPU's reciprocal square root estimate, refined
+    /// by `steps` Newton-Raphson iterations. See
+    /// [`Vector3::magnitude_recip_fast`](crate::Vector3::magnitude_recip_fast) for the
+    /// error of each step count.
+    #[inline]
+    #[must_use]
+    pub fn magnitude_recip_fast(self, steps: u32) -> f64 {
+        math::rsqrt_fast(self.magnitude_squared(), steps)
+    }
+
+    /// Normalizes the vector with [`magnitude_recip_fast`](Self::magnitude_recip_fast), trading
+    /// accuracy for speed. Zero vectors stay zero, as with [`normalize`](Self::normalize).
+    #[inline]
+    #[must_use]
+    pub fn normalize_fast(self, steps: u32) -> Self {
+        let mag_squared = self.magnitude_squared();
+        if mag_squared == 0.0 {
+            Self::zeros()
+        } else {
+            self * math::rsqrt_fast(mag_squared, steps)
+        }
+    }
+
+    /// Normalizes the vector, failing instead of returning zeros or NaNs.
+    ///
+    /// Returns [`FastVecError::NonFinite`] if a component is infinite or NaN, and
+    /// [`FastVecError::ZeroLength`] if every component is zero. Vectors whose squared length
+    /// overflows or underflows are scaled first, so any other finite vector succeeds.
+    #[inline]
+    pub fn try_normalize(self) -> core::result::Result<Self, FastVecError> {
+        self.normalize_scaled().map(|(normalized, _, _)| normalized)
+    }
+
+    /// Like [`try_normalize`](Self::try_normalize), but also returns the original length.
+    /// Returns [`FastVecError::NonFinite`] if the length itself overflows.
+    #[inline]
+    pub fn normalize_and_length(self) -> core::result::Result<(Self, f64), FastVecError> {
+        let (normalized, mag, scale) = self.normalize_scaled()?;
+        let length = mag * scale;
+        if length.is_finite() {
+            Ok((normalized, length))
+        } else {
+            Err(FastVecError::NonFinite)
+        }
+    }
+
+    /// Returns the normalized vector and its length as `mag * scale`. The length is only
+    /// split when the squared length is out of range, in which case the vector is divided by
+    /// its largest absolute component before squaring.
+    #[inline]
+    fn normalize_scaled(self) -> core::result::Result<(Self, f64, f64), FastVecError> {
+        let mag_squared = self.magnitude_squared();
+        if mag_squared.is_normal() && mag_squared.is_finite() {
+            let mag = math::sqrt(mag_squared);
+            return Ok((self * (1.0 / mag), mag, 1.0));
+        }
+        if !self.is_finite() {
+            return Err(FastVecError::NonFinite);
+        }
+        let scale = self.iter().fold(0.0, |max, c| f64::max(max, c.abs()));
+        if scale == 0.0 {
+            return Err(FastVecError::ZeroLength);
+        }
+        let scaled = self.div_exact(scale);
+        let mag = scaled.magnitude();
+        Ok((scaled * (1.0 / mag), mag, scale))
+    }
+
+    /// Normalizes the vector, returning `fallback` where [`try_normalize`](Self::try_normalize)
+    /// would fail.
+    #[inline]
+    #[must_use]
+    pub fn normalize_or(self, fallback: Self) -> Self {
+        self.try_normalize().unwrap_or(fallback)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_normalized(self, tolerance: f64) -> bool {
+        (self.magnitude() - 1.0).abs() <= tolerance
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_finite(self) -> bool {
+        self.iter().all(f64::is_finite)
+    }
+
+    /// Divides each component by `rhs` with IEEE division, so the result is correctly
+    /// rounded. This is what `/` and `/=` do unless the `fast-math` feature is enabled.
+    #[inline]
+    #[must_use]
+    pub fn div_exact(self, rhs: f64) -> Self {
+        Self(self.0 / f64x2::splat(rhs))
+    }
+
+    /// Multiplies by `1.0 / rhs`, which is faster than [`div_exact`](Self::div_exact) but may
+    /// differ from it by one ulp per component. This is what `/` and `/=` do when the
+    /// `fast-math` feature is enabled.
+    #[inline]
+    #[must_use]
+    pub fn div_fast(self, rhs: f64) -> Self {
+        Self(self.0 * f64x2::splat(1.0 / rhs))
+    }
+
+    /// Divides by `rhs`, failing if it is zero or if the result is not finite.
+    #[inline]
+    pub fn checked_div(self, rhs: f64) -> core::result::Result<Self, FastVecError> {
+        if rhs == 0.0 {
+            return Err(FastVecError::DivisionByZero);
+        }
+        let result = self / rhs;
+        if result.is_finite() {
+            Ok(result)
+        } else {
+            Err(FastVecError::NonFinite)
+        }
+    }
+
     #[inline]
     #[must_use]
     pub fn cross(self, rhs: Self) -> f64 {
         self.x() * rhs.y() - self.y() * rhs.x()
     }
 
+    /// [`cross`](Self::cross) computed by Kahan's difference of products, accurate to 1.5 ulps.
+    /// See [`dot_fma`](Self::dot_fma) about performance.
+    #[inline]
+    #[must_use]
+    pub fn cross_fma(self, rhs: Self) -> f64 {
+        math::difference_of_products(self.x(), rhs.y(), self.y(), rhs.x())
+    }
+
+    /// Returns the vector rotated 90 degrees counter-clockwise, `(-y, x)`.
+    #[inline]
+    #[must_use]
+    pub fn perp(self) -> Self {
+        Self::new(-self.y(), self.x())
+    }
+
+    /// Returns the unit vector at `theta` radians from `+x`, i.e. the complex number
+    /// `e^(i * theta)`.
+    #[inline]
+    #[must_use]
+    pub fn from_angle(theta: f64) -> Self {
+        let (sin, cos) = math::sin_cos(theta);
+        Self::new(cos, sin)
+    }
+
+    /// Multiplies as complex numbers `x + yi`, which rotates and scales `self` by `rhs`.
+    #[inline]
+    #[must_use]
+    pub fn complex_mul(self, rhs: Self) -> Self {
+        let re = simd_swizzle!(self.0, [0, 0]) * rhs.0;
+        let im = simd_swizzle!(self.0, [1, 1]) * simd_swizzle!(rhs.0, [1, 0]);
+        Self(re + im * f64x2::from_array([-1.0, 1.0]))
+    }
+
+    /// Divides as complex numbers `x + yi`. Dividing by zero yields non-finite components.
+    ///
+    /// Uses Smith's algorithm, which divides through by the larger component of `rhs` instead
+    /// of its squared magnitude, so components near the ends of the `f64` range don't
+    /// overflow or underflow.
+    #[inline]
+    #[must_use]
+    pub fn complex_div(self, rhs: Self) -> Self {
+        let [a, b] = self.to_array();
+        let [c, d] = rhs.to_array();
+        if c.abs() >= d.abs() {
+            let r = d / c;
+            let denominator = c + d * r;
+            Self::new((a + b * r) / denominator, (b - a * r) / denominator)
+        } else {
+            let r = c / d;
+            let denominator = c * r + d;
+            Self::new((a * r + b) / denominator, (b * r - a) / denominator)
+        }
+    }
+
+    /// Returns the complex conjugate `(x, -y)`.
+    #[inline]
+    #[must_use]
+    pub fn conj(self) -> Self {
+        Self(self.0 * f64x2::from_array([1.0, -1.0]))
+    }
+
+    /// Returns the complex argument in `[-pi, pi]`.
+    #[inline]
+    #[must_use]
+    pub fn arg(self) -> f64 {
+        math::atan2(self.y(), self.x())
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn complex_exp(self) -> Self {
+        Self::from_angle(self.y()) * math::exp(self.x())
+    }
+
+    /// Returns the principal natural logarithm. The logarithm of zero has a real part of
+    /// negative infinity.
+    #[inline]
+    #[must_use]
+    pub fn complex_ln(self) -> Self {
+        Self::new(math::ln(self.magnitude()), self.arg())
+    }
+
+    /// Raises to a real power using the principal branch.
+    #[inline]
+    #[must_use]
+    pub fn complex_powf(self, exponent: f64) -> Self {
+        Self::from_angle(self.arg() * exponent) * math::powf(self.magnitude(), exponent)
+    }
+
     #[inline]
     #[must_use]
     pub fn distance(self, rhs: Self) -> f64 {
@@ -97,9 +442,121 @@ impl Vector2 {
     pub fn distance_squared(self, rhs: Self) -> f64 {
         (self - rhs).magnitude_squared()
     }
+
+    /// Distance computed with [`dot_fma`](Self::dot_fma).
+    #[inline]
+    #[must_use]
+    pub fn distance_fma(self, rhs: Self) -> f64 {
+        let d = self - rhs;
+        math::sqrt(d.dot_fma(d))
+    }
+
+    /// Linear interpolation, `self * (1 - t) + rhs * t`. For finite inputs it returns
+    /// exactly `self` at `t = 0` and `rhs` at `t = 1`.
+    #[inline]
+    #[must_use]
+    pub fn lerp(self, rhs: Self, t: f64) -> Self {
+        self * (1.0 - t) + rhs * t
+    }
+
+    /// [`lerp`](Self::lerp) computed as `t * rhs + (self - t * self)` with two fused
+    /// multiply-adds per component, so each component rounds twice instead of four times.
+    /// Also exact at `t = 0` and `t = 1`.
+    #[inline]
+    #[must_use]
+    pub fn lerp_fma(self, rhs: Self, t: f64) -> Self {
+        let lerp = |a: f64, b: f64| math::mul_add(t, b, math::mul_add(-t, a, a));
+        Self::new(lerp(self.x(), rhs.x()), lerp(self.y(), rhs.y()))
+    }
+
+    /// Returns the component-wise minimum. A NaN component is ignored in favour of the other.
+    #[inline]
+    #[must_use]
+    pub fn min(self, rhs: Self) -> Self {
+        Self(self.0.simd_min(rhs.0))
+    }
+
+    /// Returns the component-wise maximum. A NaN component is ignored in favour of the other.
+    #[inline]
+    #[must_use]
+    pub fn max(self, rhs: Self) -> Self {
+        Self(self.0.simd_max(rhs.0))
+    }
+
+    /// Returns `true` if every component differs from `rhs` by at most `epsilon`.
+    #[inline]
+    #[must_use]
+    pub fn abs_diff_eq(self, rhs: Self, epsilon: f64) -> bool {
+        self.iter()
+            .zip(rhs.iter())
+            .all(|(a, b)| approx::abs_diff_eq(a, b, epsilon))
+    }
+
+    /// Returns `true` if every component differs from `rhs` by at most `epsilon`, or by at
+    /// most `max_relative` times the larger of the two magnitudes.
+    #[inline]
+    #[must_use]
+    pub fn relative_eq(self, rhs: Self, epsilon: f64, max_relative: f64) -> bool {
+        self.iter()
+            .zip(rhs.iter())
+            .all(|(a, b)| approx::relative_eq(a, b, epsilon, max_relative))
+    }
+
+    /// Returns `true` if every component differs from `rhs` by at most `epsilon`, or is at
+    /// most `max_ulps` representable values away with the same sign.
+    #[inline]
+    #[must_use]
+    pub fn ulps_eq(self, rhs: Self, epsilon: f64, max_ulps: u64) -> bool {
+        self.iter()
+            .zip(rhs.iter())
+            .all(|(a, b)| approx::ulps_eq(a, b, epsilon, max_ulps))
+    }
+
+    /// Returns the arithmetic mean of the vectors, or `None` if the iterator is empty.
+    #[must_use]
+    pub fn mean<I>(iter: I) -> Option<Self>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Self>,
+    {
+        let mut sum = Self::zeros();
+        let mut count = 0usize;
+        for v in iter {
+            sum += *v.borrow();
+            count += 1;
+        }
+        (count > 0).then(|| sum / count as f64)
+    }
+
+    /// Alias of [`Vector2::mean`] for when the vectors represent points.
+    #[inline]
+    #[must_use]
+    pub fn centroid<I>(iter: I) -> Option<Self>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Self>,
+    {
+        Self::mean(iter)
+    }
+
+    /// Returns the mean of the vectors weighted by the paired scalars, or `None` if the
+    /// weights sum to zero (which includes the empty iterator).
+    #[must_use]
+    pub fn weighted_mean<I>(iter: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = (Self, f64)>,
+    {
+        let mut sum = Self::zeros();
+        let mut total_weight = 0.0;
+        for (v, weight) in iter {
+            sum += v * weight;
+            total_weight += weight;
+        }
+        (total_weight != 0.0).then(|| sum / total_weight)
+    }
 }
 
-impl std::ops::Add for Vector2 {
+impl core::ops::Add for Vector2 {
     type Output = Vector2;
 
     #[inline]
@@ -108,7 +565,7 @@ impl std::ops::Add for Vector2 {
     }
 }
 
-impl std::ops::Sub for Vector2 {
+impl core::ops::Sub for Vector2 {
     type Output = Vector2;
 
     #[inline]
@@ -117,7 +574,7 @@ impl std::ops::Sub for Vector2 {
     }
 }
 
-impl std::ops::Mul<f64> for Vector2 {
+impl core::ops::Mul<f64> for Vector2 {
     type Output = Vector2;
 
     #[inline]
@@ -126,7 +583,7 @@ impl std::ops::Mul<f64> for Vector2 {
     }
 }
 
-impl std::ops::Mul<Vector2> for f64 {
+impl core::ops::Mul<Vector2> for f64 {
     type Output = Vector2;
 
     #[inline]
@@ -135,16 +592,22 @@ impl std::ops::Mul<Vector2> for f64 {
     }
 }
 
-impl std::ops::Div<f64> for Vector2 {
+impl core::ops::Div<f64> for Vector2 {
     type Output = Vector2;
 
+    /// Uses [`div_exact`](Vector2::div_exact), or [`div_fast`](Vector2::div_fast) with the
+    /// `fast-math` feature.
     #[inline]
     fn div(self, rhs: f64) -> Self::Output {
-        Self(self.0 / f64x2::splat(rhs))
+        if cfg!(feature = "fast-math") {
+            self.div_fast(rhs)
+        } else {
+            self.div_exact(rhs)
+        }
     }
 }
 
-impl std::ops::Neg for Vector2 {
+impl core::ops::Neg for Vector2 {
     type Output = Vector2;
 
     #[inline]
@@ -153,31 +616,115 @@ impl std::ops::Neg for Vector2 {
     }
 }
 
-impl std::ops::AddAssign for Vector2 {
+impl core::ops::AddAssign for Vector2 {
     #[inline]
     fn add_assign(&mut self, rhs: Self) {
         self.0 += rhs.0;
     }
 }
 
-impl std::ops::SubAssign for Vector2 {
+impl core::ops::SubAssign for Vector2 {
     #[inline]
     fn sub_assign(&mut self, rhs: Self) {
         self.0 -= rhs.0;
     }
 }
 
-impl std::ops::MulAssign<f64> for Vector2 {
+impl core::ops::MulAssign<f64> for Vector2 {
     #[inline]
     fn mul_assign(&mut self, rhs: f64) {
         self.0 *= f64x2::splat(rhs);
     }
 }
 
-impl std::ops::DivAssign<f64> for Vector2 {
+impl core::ops::DivAssign<f64> for Vector2 {
     #[inline]
     fn div_assign(&mut self, rhs: f64) {
-        self.0 /= f64x2::splat(rhs);
+        *self = *self / rhs;
+    }
+}
+
+impl core::ops::Index<usize> for Vector2 {
+    type Output = f64;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl core::ops::IndexMut<usize> for Vector2 {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+impl From<[f64; 2]> for Vector2 {
+    #[inline]
+    fn from(array: [f64; 2]) -> Self {
+        Self::from_array(array)
+    }
+}
+
+/// Builds a vector from a slice of exactly two components.
+impl TryFrom<&[f64]> for Vector2 {
+    type Error = ParseVectorError;
+
+    #[inline]
+    fn try_from(slice: &[f64]) -> core::result::Result<Self, Self::Error> {
+        let array =
+            <[f64; 2]>::try_from(slice).map_err(|_| ParseVectorError::WrongComponentCount {
+                expected: 2,
+                found: slice.len(),
+            })?;
+        Ok(Self::from_array(array))
+    }
+}
+
+impl From<(f64, f64)> for Vector2 {
+    #[inline]
+    fn from((x, y): (f64, f64)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl From<Vector2> for [f64; 2] {
+    #[inline]
+    fn from(v: Vector2) -> Self {
+        v.to_array()
+    }
+}
+
+impl From<Vector2> for (f64, f64) {
+    #[inline]
+    fn from(v: Vector2) -> Self {
+        (v.x(), v.y())
+    }
+}
+
+impl core::iter::Sum for Vector2 {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zeros(), |acc, v| acc + v)
+    }
+}
+
+impl<'a> core::iter::Sum<&'a Vector2> for Vector2 {
+    fn sum<I: Iterator<Item = &'a Vector2>>(iter: I) -> Self {
+        iter.fold(Self::zeros(), |acc, v| acc + *v)
+    }
+}
+
+/// Multiplies component-wise, starting from [`ONE`](Self::ONE).
+impl core::iter::Product for Vector2 {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |acc, v| Self(acc.0 * v.0))
+    }
+}
+
+impl<'a> core::iter::Product<&'a Vector2> for Vector2 {
+    fn product<I: Iterator<Item = &'a Vector2>>(iter: I) -> Self {
+        iter.copied().product()
     }
 }
 
@@ -200,6 +747,7 @@ impl Copy for Vector2 {}
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
 
     #[test]
     fn test_new() {
@@ -302,8 +850,7 @@ mod tests {
         let normalized = a.normalize();
         let mag = normalized.magnitude();
         assert!((mag - 1.0).abs() < 1e-10);
-        assert!((normalized.x() - 0.6).abs() < 1e-10);
-        assert!((normalized.y() - 0.8).abs() < 1e-10);
+        crate::assert_vec_approx_eq!(normalized, Vector2::new(0.6, 0.8));
     }
 
     #[test]
@@ -346,6 +893,38 @@ mod tests {
         assert_eq!(dist_sq, 25.0);
     }
 
+    #[test]
+    fn test_min_max() {
+        let a = Vector2::new(1.0, f64::NAN);
+        let b = Vector2::new(0.5, 3.0);
+        assert_eq!(a.min(b), Vector2::new(0.5, 3.0));
+        assert_eq!(a.max(b), Vector2::new(1.0, 3.0));
+    }
+
+    #[test]
+    fn test_fma_cancellation() {
+        let e = 2f64.powi(-30);
+        let a = Vector2::new(1.0 + e, 1.0);
+        let b = Vector2::new(1.0 - e, -1.0);
+        assert_eq!(a.dot(b), 0.0);
+        assert_eq!(a.dot_fma(b), -e * e);
+        let c = Vector2::new(1.0, 1.0 - e);
+        assert_eq!(a.cross(c), 0.0);
+        assert_eq!(a.cross_fma(c), -e * e);
+        assert_eq!(Vector2::ZERO.distance_fma(Vector2::new(3.0, 4.0)), 5.0);
+    }
+
+    #[test]
+    fn test_lerp() {
+        let a = Vector2::new(1.0, -2.0);
+        let b = Vector2::new(0.3, 5.0);
+        for lerp in [Vector2::lerp, Vector2::lerp_fma] {
+            assert_eq!(lerp(a, b, 0.0), a);
+            assert_eq!(lerp(a, b, 1.0), b);
+            assert!(lerp(a, b, 0.5).abs_diff_eq(Vector2::new(0.65, 1.5), 1e-15));
+        }
+    }
+
     #[test]
     fn test_add_assign() {
         let mut a = Vector2::new(1.0, 2.0);
@@ -376,6 +955,28 @@ mod tests {
         assert_eq!(a, Vector2::new(1.0, 2.0));
     }
 
+    #[test]
+    fn test_div_exact_and_fast() {
+        let a = Vector2::new(5.0, 7.0);
+        assert_eq!(a.div_exact(3.0), Vector2::new(5.0 / 3.0, 7.0 / 3.0));
+        assert_eq!(
+            a.div_fast(3.0),
+            Vector2::new(5.0 * (1.0 / 3.0), 7.0 * (1.0 / 3.0))
+        );
+        assert_ne!(a.div_exact(3.0), a.div_fast(3.0));
+    }
+
+    #[test]
+    fn test_div_operator_policy() {
+        let a = Vector2::new(5.0, 7.0);
+        let expected = if cfg!(feature = "fast-math") {
+            a.div_fast(3.0)
+        } else {
+            a.div_exact(3.0)
+        };
+        assert_eq!(a / 3.0, expected);
+    }
+
     #[test]
     fn test_equality() {
         let a = Vector2::new(1.0, 2.0);
@@ -393,4 +994,458 @@ mod tests {
         assert_eq!(a, b);
         assert_eq!(a, c);
     }
+
+    #[test]
+    fn test_index() {
+        let mut v = Vector2::new(1.0, 2.0);
+        assert_eq!(v[0], 1.0);
+        assert_eq!(v[1], 2.0);
+        v[1] = 5.0;
+        assert_eq!(v, Vector2::new(1.0, 5.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_out_of_bounds() {
+        let v = Vector2::new(1.0, 2.0);
+        let _ = v[2];
+    }
+
+    #[test]
+    fn test_array_conversions() {
+        let v = Vector2::from_array([1.0, 2.0]);
+        assert_eq!(v, Vector2::new(1.0, 2.0));
+        assert_eq!(v.to_array(), [1.0, 2.0]);
+        assert_eq!(Vector2::from([3.0, 4.0]), Vector2::new(3.0, 4.0));
+        let array: [f64; 2] = v.into();
+        assert_eq!(array, [1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_tuple_conversions() {
+        let v = Vector2::from((1.0, 2.0));
+        assert_eq!(v, Vector2::new(1.0, 2.0));
+        let tuple: (f64, f64) = v.into();
+        assert_eq!(tuple, (1.0, 2.0));
+    }
+
+    #[test]
+    fn test_slice_conversions() {
+        let v = Vector2::from_slice(&[1.0, 2.0, 3.0]);
+        assert_eq!(v, Vector2::new(1.0, 2.0));
+        let mut out = [0.0; 3];
+        v.write_to_slice(&mut out);
+        assert_eq!(out, [1.0, 2.0, 0.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_slice_too_short() {
+        let _ = Vector2::from_slice(&[1.0]);
+    }
+
+    #[test]
+    fn test_iter() {
+        let v = Vector2::new(1.0, 2.0);
+        let components: Vec<f64> = v.iter().collect();
+        assert_eq!(components, vec![1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_display() {
+        let v = Vector2::new(1.0, -2.5);
+        assert_eq!(format!("{v}"), "(1, -2.5)");
+        assert_eq!(format!("{v:.3}"), "(1.000, -2.500)");
+        assert_eq!(format!("{v:5.1}"), "(  1.0,  -2.5)");
+    }
+
+    #[test]
+    fn test_exp() {
+        let v = Vector2::new(1.0, -2.5);
+        assert_eq!(format!("{v:e}"), "(1e0, -2.5e0)");
+        assert_eq!(format!("{v:E}"), "(1E0, -2.5E0)");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("(1, -2.5)".parse::<Vector2>(), Ok(Vector2::new(1.0, -2.5)));
+        assert_eq!("[1, 2]".parse::<Vector2>(), Ok(Vector2::new(1.0, 2.0)));
+        assert!("(1, 2, 3)".parse::<Vector2>().is_err());
+    }
+
+    #[test]
+    fn test_product() {
+        let vectors = [Vector2::new(1.0, 2.0), Vector2::new(4.0, -5.0)];
+        assert_eq!(
+            vectors.iter().product::<Vector2>(),
+            Vector2::new(4.0, -10.0)
+        );
+        assert_eq!(
+            vectors.into_iter().product::<Vector2>(),
+            Vector2::new(4.0, -10.0)
+        );
+        assert_eq!(
+            core::iter::empty::<Vector2>().product::<Vector2>(),
+            Vector2::ONE
+        );
+    }
+
+    #[test]
+    fn test_try_from_slice() {
+        let components = [2.0, -3.0];
+        assert_eq!(
+            Vector2::try_from(&components[..]),
+            Ok(Vector2::from_array(components))
+        );
+        for slice in [&[1.0][..], &[1.0; 3]] {
+            assert_eq!(
+                Vector2::try_from(slice),
+                Err(ParseVectorError::WrongComponentCount {
+                    expected: 2,
+                    found: slice.len()
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn test_sum() {
+        let points = vec![
+            Vector2::new(1.0, 2.0),
+            Vector2::new(3.0, 4.0),
+            Vector2::new(5.0, 6.0),
+        ];
+        assert_eq!(points.iter().sum::<Vector2>(), Vector2::new(9.0, 12.0));
+        assert_eq!(points.into_iter().sum::<Vector2>(), Vector2::new(9.0, 12.0));
+        assert_eq!(
+            Vec::<Vector2>::new().into_iter().sum::<Vector2>(),
+            Vector2::zeros()
+        );
+    }
+
+    #[test]
+    fn test_mean() {
+        let points = vec![
+            Vector2::new(1.0, 2.0),
+            Vector2::new(3.0, 4.0),
+            Vector2::new(5.0, 6.0),
+        ];
+        assert_eq!(Vector2::mean(&points), Some(Vector2::new(3.0, 4.0)));
+        assert_eq!(Vector2::centroid(points), Some(Vector2::new(3.0, 4.0)));
+        assert_eq!(Vector2::mean(Vec::<Vector2>::new()), None);
+    }
+
+    #[test]
+    fn test_weighted_mean() {
+        let weighted = [(Vector2::new(0.0, 0.0), 1.0), (Vector2::new(4.0, 8.0), 3.0)];
+        assert_eq!(
+            Vector2::weighted_mean(weighted),
+            Some(Vector2::new(3.0, 6.0))
+        );
+        assert_eq!(
+            Vector2::weighted_mean([(Vector2::new(4.0, 8.0), 0.0)]),
+            None
+        );
+        assert_eq!(Vector2::weighted_mean([]), None);
+    }
+
+    #[test]
+    fn test_abs_diff_eq() {
+        assert!(Vector2::new(1.0, 2.0).abs_diff_eq(Vector2::new(1.0 + 1e-12, 2.0), 1e-10));
+        assert!(!Vector2::new(1.0, 2.0).abs_diff_eq(Vector2::new(1.0, 2.1), 1e-10));
+        assert!(Vector2::INFINITY.abs_diff_eq(Vector2::INFINITY, 1e-10));
+        assert!(Vector2::NEG_INFINITY.ulps_eq(Vector2::NEG_INFINITY, 0.0, 0));
+        assert!(!Vector2::INFINITY.abs_diff_eq(Vector2::NEG_INFINITY, 1e-10));
+        assert!(!Vector2::NAN.abs_diff_eq(Vector2::NAN, 1e-10));
+    }
+
+    #[test]
+    fn test_relative_eq() {
+        assert!(Vector2::new(1e10, 1.0).relative_eq(Vector2::new(1e10 + 1.0, 1.0), 0.0, 1e-9));
+        assert!(!Vector2::new(1.0, 2.0).relative_eq(Vector2::new(1.0, 2.1), 0.0, 1e-9));
+    }
+
+    #[test]
+    fn test_ulps_eq() {
+        assert!(Vector2::new(1.0, 2.0).ulps_eq(
+            Vector2::new(f64::from_bits(1.0f64.to_bits() + 3), 2.0),
+            0.0,
+            3
+        ));
+        assert!(!Vector2::new(1.0, 2.0).ulps_eq(
+            Vector2::new(f64::from_bits(1.0f64.to_bits() + 3), 2.0),
+            0.0,
+            2
+        ));
+    }
+
+    #[test]
+    fn test_assert_vec_eq_macro() {
+        crate::assert_vec_eq!(Vector2::new(1.0, 2.0), Vector2::new(1.0, 2.0));
+        crate::assert_vec_approx_eq!(Vector2::new(1.0, 2.0), Vector2::new(1.0, 2.1), 0.2);
+    }
+
+    #[test]
+    #[should_panic(expected = "error: [")]
+    fn test_assert_vec_approx_eq_macro_failure() {
+        crate::assert_vec_approx_eq!(Vector2::new(1.0, 2.0), Vector2::new(1.0, 2.1));
+    }
+
+    #[test]
+    fn test_try_normalize() {
+        crate::assert_vec_approx_eq!(
+            Vector2::new(3.0, 4.0).try_normalize().unwrap(),
+            Vector2::new(0.6, 0.8)
+        );
+        assert_eq!(
+            Vector2::zeros().try_normalize(),
+            Err(FastVecError::ZeroLength)
+        );
+        assert_eq!(
+            Vector2::new(f64::NAN, 1.0).try_normalize(),
+            Err(FastVecError::NonFinite)
+        );
+        assert_eq!(
+            Vector2::new(f64::INFINITY, 1.0).try_normalize(),
+            Err(FastVecError::NonFinite)
+        );
+        // Squared lengths that overflow or underflow are still valid directions.
+        assert_eq!(Vector2::new(1e200, 0.0).try_normalize(), Ok(Vector2::X));
+        assert_eq!(Vector2::new(1e-200, 0.0).try_normalize(), Ok(Vector2::X));
+        assert_eq!(Vector2::new(0.0, -5e-324).try_normalize(), Ok(-Vector2::Y));
+        crate::assert_vec_approx_eq!(
+            Vector2::new(1e200, 1e200).try_normalize().unwrap(),
+            Vector2::ONE * (core::f64::consts::FRAC_1_SQRT_2)
+        );
+    }
+
+    #[test]
+    fn test_normalize_and_length() {
+        let (normalized, length) = Vector2::new(3.0, 4.0).normalize_and_length().unwrap();
+        crate::assert_vec_approx_eq!(normalized, Vector2::new(0.6, 0.8));
+        assert_eq!(length, 5.0);
+        assert_eq!(
+            Vector2::zeros().normalize_and_length(),
+            Err(FastVecError::ZeroLength)
+        );
+        let (normalized, length) = Vector2::new(1e200, 0.0).normalize_and_length().unwrap();
+        assert_eq!((normalized, length), (Vector2::X, 1e200));
+        let (normalized, length) = Vector2::new(1e-200, 0.0).normalize_and_length().unwrap();
+        assert_eq!((normalized, length), (Vector2::X, 1e-200));
+        assert_eq!(
+            Vector2::new(f64::MAX, f64::MAX).normalize_and_length(),
+            Err(FastVecError::NonFinite)
+        );
+        crate::assert_vec_approx_eq!(
+            Vector2::new(f64::MAX, f64::MAX).try_normalize().unwrap(),
+            Vector2::new(1.0, 1.0).normalize()
+        );
+    }
+
+    #[test]
+    fn test_normalize_or() {
+        let fallback = Vector2::new(1.0, 0.0);
+        crate::assert_vec_approx_eq!(
+            Vector2::new(3.0, 4.0).normalize_or(fallback),
+            Vector2::new(0.6, 0.8)
+        );
+        assert_eq!(Vector2::zeros().normalize_or(fallback), fallback);
+        assert_eq!(Vector2::new(f64::NAN, 1.0).normalize_or(fallback), fallback);
+    }
+
+    #[test]
+    fn test_is_normalized() {
+        assert!(Vector2::new(0.6, 0.8).is_normalized(1e-12));
+        assert!(!Vector2::new(3.0, 4.0).is_normalized(1e-12));
+        assert!(!Vector2::new(f64::NAN, 1.0).is_normalized(1e-12));
+    }
+
+    #[test]
+    fn test_checked_div() {
+        assert_eq!(
+            Vector2::new(3.0, 4.0).checked_div(2.0),
+            Ok(Vector2::new(1.5, 2.0))
+        );
+        assert_eq!(
+            Vector2::new(3.0, 4.0).checked_div(0.0),
+            Err(FastVecError::DivisionByZero)
+        );
+        assert_eq!(
+            Vector2::new(3.0, 4.0).checked_div(1e-320),
+            Err(FastVecError::NonFinite)
+        );
+        assert_eq!(
+            Vector2::new(f64::NAN, 1.0).checked_div(2.0),
+            Err(FastVecError::NonFinite)
+        );
+    }
+
+    #[test]
+    fn test_perp() {
+        let a = Vector2::new(1.0, 2.0);
+        let p = a.perp();
+        assert_eq!(p, Vector2::new(-2.0, 1.0));
+        assert_eq!(a.dot(p), 0.0);
+        assert_eq!(a.cross(p), a.magnitude_squared());
+    }
+
+    #[test]
+    fn test_from_angle() {
+        crate::assert_vec_approx_eq!(
+            Vector2::from_angle(core::f64::consts::FRAC_PI_2),
+            Vector2::new(0.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn test_complex_mul() {
+        let a = Vector2::new(1.0, 2.0);
+        let b = Vector2::new(3.0, -4.0);
+        assert_eq!(a.complex_mul(b), Vector2::new(11.0, 2.0));
+        let i = Vector2::new(0.0, 1.0);
+        assert_eq!(i.complex_mul(i), Vector2::new(-1.0, 0.0));
+        assert_eq!(a.complex_mul(i), a.perp());
+    }
+
+    #[test]
+    fn test_complex_div() {
+        let a = Vector2::new(11.0, 2.0);
+        let b = Vector2::new(3.0, -4.0);
+        crate::assert_vec_approx_eq!(a.complex_div(b), Vector2::new(1.0, 2.0));
+        assert!(!a.complex_div(Vector2::zeros()).is_finite());
+        // |rhs|^2 would overflow (or underflow) here.
+        let big = Vector2::new(3e200, 4e200);
+        assert_eq!(big.complex_div(big), Vector2::X);
+        assert!(Vector2::new(1e300, 0.0).complex_div(big).relative_eq(
+            Vector2::new(0.12e100, -0.16e100),
+            0.0,
+            1e-15
+        ));
+        let tiny = Vector2::new(-4e-200, 3e-200);
+        assert_eq!(tiny.complex_div(tiny), Vector2::X);
+        assert!(Vector2::Y.complex_div(tiny).relative_eq(
+            Vector2::new(0.12e200, -0.16e200),
+            0.0,
+            1e-15
+        ));
+    }
+
+    #[test]
+    fn test_conj_and_arg() {
+        let a = Vector2::new(1.0, 1.0);
+        assert_eq!(a.conj(), Vector2::new(1.0, -1.0));
+        assert_eq!(a.arg(), core::f64::consts::FRAC_PI_4);
+        assert_eq!(a.complex_mul(a.conj()), Vector2::new(2.0, 0.0));
+    }
+
+    #[test]
+    fn test_complex_exp_ln() {
+        let pi = core::f64::consts::PI;
+        crate::assert_vec_approx_eq!(Vector2::new(0.0, pi).complex_exp(), Vector2::new(-1.0, 0.0));
+        let z = Vector2::new(0.5, -2.0);
+        crate::assert_vec_approx_eq!(z.complex_ln().complex_exp(), z);
+        crate::assert_vec_approx_eq!(z.complex_exp().complex_ln(), z);
+        assert_eq!(Vector2::zeros().complex_ln().x(), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_complex_powf() {
+        let z = Vector2::new(1.0, 1.0);
+        crate::assert_vec_approx_eq!(z.complex_powf(2.0), z.complex_mul(z));
+        let root = Vector2::new(-4.0, 0.0).complex_powf(0.5);
+        crate::assert_vec_approx_eq!(root, Vector2::new(0.0, 2.0));
+        assert_eq!(Vector2::zeros().complex_powf(2.0), Vector2::zeros());
+    }
+
+    #[test]
+    fn test_constants() {
+        assert_eq!(Vector2::ZERO, Vector2::zeros());
+        assert_eq!(Vector2::ONE, Vector2::ones());
+        assert_eq!(Vector2::X.cross(Vector2::Y), 1.0);
+        assert_eq!(Vector2::NEG_X, -Vector2::X);
+        assert_eq!(Vector2::NEG_Y, -Vector2::Y);
+        assert!(Vector2::NAN.x().is_nan() && Vector2::NAN.y().is_nan());
+        assert_eq!(Vector2::INFINITY.x(), f64::INFINITY);
+        assert_eq!(Vector2::NEG_INFINITY.y(), f64::NEG_INFINITY);
+        assert_eq!(Vector2::MIN.x(), f64::MIN);
+        assert_eq!(Vector2::MAX.y(), f64::MAX);
+    }
+
+    #[test]
+    fn test_const_arithmetic() {
+        const DIRECTIONS: [Vector2; 4] = [
+            Vector2::X,
+            Vector2::Y.const_mul(2.0),
+            Vector2::X.const_add(Vector2::Y).const_div(2.0),
+            Vector2::ONE.const_sub(Vector2::X).const_neg(),
+        ];
+        const DOT: f64 = Vector2::new(1.0, 2.0).const_dot(Vector2::new(3.0, 4.0));
+        assert_eq!(DIRECTIONS[1], Vector2::new(0.0, 2.0));
+        assert_eq!(DIRECTIONS[2], Vector2::new(0.5, 0.5));
+        assert_eq!(DIRECTIONS[3], Vector2::new(0.0, -1.0));
+        assert_eq!(DOT, 11.0);
+
+        let a = Vector2::new(1.5, -2.0);
+        let b = Vector2::new(0.25, 3.0);
+        assert_eq!(a.const_add(b), a + b);
+        assert_eq!(a.const_sub(b), a - b);
+        assert_eq!(a.const_mul(3.0), a * 3.0);
+        assert_eq!(a.const_div(3.0), a / 3.0);
+        assert_eq!(a.const_neg(), -a);
+        assert_eq!(a.const_dot(b), a.dot(b));
+        for (a, b) in [
+            (Vector2::new(0.1, 0.2), Vector2::new(0.7, -1.3)),
+            (Vector2::ZERO, -Vector2::ONE),
+            (Vector2::NAN, Vector2::ONE),
+        ] {
+            assert_eq!(a.const_dot(b).to_bits(), a.dot(b).to_bits());
+        }
+    }
+
+    /// Compares bits, except that any NaN matches any NaN: `Display` and the division
+    /// policies do not preserve NaN payloads or signs.
+    fn assert_bit_exact(parsed: Vector2, original: Vector2) {
+        for (a, b) in parsed.iter().zip(original.iter()) {
+            if b.is_nan() {
+                assert!(a.is_nan(), "expected NaN, got {a}");
+            } else {
+                assert_eq!(a.to_bits(), b.to_bits());
+            }
+        }
+    }
+
+    #[test]
+    fn test_nan_round_trip_is_canonical() {
+        let nan = f64::from_bits(0xfff8_0000_dead_beef);
+        let v = Vector2::from_array([nan; 2]);
+        let parsed: Vector2 = v.to_string().parse().unwrap();
+        for component in parsed.iter() {
+            assert_eq!(component.to_bits(), f64::NAN.to_bits());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn prop_display_round_trip(x in any::<f64>(), y in any::<f64>()) {
+            let v = Vector2::new(x, y);
+            assert_bit_exact(v.to_string().parse().unwrap(), v);
+        }
+
+        #[test]
+        fn prop_exp_round_trip(x in any::<f64>(), y in any::<f64>()) {
+            let v = Vector2::new(x, y);
+            assert_bit_exact(format!("{v:e}").parse().unwrap(), v);
+            assert_bit_exact(format!("{v:E}").parse().unwrap(), v);
+        }
+
+        #[test]
+        fn prop_div_matches_div_assign(x in any::<f64>(), y in any::<f64>(), s in any::<f64>()) {
+            let v = Vector2::new(x, y);
+            let mut assigned = v;
+            assigned /= s;
+            assert_bit_exact(assigned, v / s);
+            let policy = if cfg!(feature = "fast-math") { v.div_fast(s) } else { v.div_exact(s) };
+            assert_bit_exact(v / s, policy);
+        }
+    }
 }
@@ -1,7 +1,16 @@
-use std::fmt::{Debug, Formatter, Result};
-use std::simd::f64x4;
-use std::simd::num::SimdFloat;
+use crate::FastVecError;
+use crate::approx;
+use crate::backend::{SimdFloat, f64x4, simd_swizzle};
+use crate::format::{ParseVectorError, parse_components, write_components};
+use crate::math;
+use core::borrow::Borrow;
+use core::fmt::{Debug, Display, Formatter, LowerExp, Result, UpperExp};
+use core::str::FromStr;
 
+/// A 3D vector stored in a 4-lane SIMD register.
+///
+/// The fourth lane is padding. Every operation keeps it at zero, so lane-wide reductions such
+/// as [`dot`](Self::dot) only see the three components, even when they are infinite or NaN.
 pub struct Vector3(f64x4);
 
 impl Debug for Vector3 {
@@ -10,41 +19,111 @@ impl Debug for Vector3 {
     }
 }
 
+/// Writes `(x, y, z)`. Finite values and infinities round-trip exactly through [`FromStr`]; every
+/// NaN is written as `NaN`, so its sign and payload are lost.
+impl Display for Vector3 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write_components(f, &self.to_array(), Display::fmt)
+    }
+}
+
+impl LowerExp for Vector3 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write_components(f, &self.to_array(), LowerExp::fmt)
+    }
+}
+
+impl UpperExp for Vector3 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write_components(f, &self.to_array(), UpperExp::fmt)
+    }
+}
+
+/// Parses `(x, y, z)` or `[x, y, z]`. A `NaN` component parses as the canonical positive quiet NaN.
+impl FromStr for Vector3 {
+    type Err = ParseVectorError;
+
+    fn from_str(s: &str) -> core::result::Result<Self, Self::Err> {
+        parse_components(s).map(Self::from_array)
+    }
+}
+
 impl Vector3 {
+    pub const ZERO: Self = Self::new(0.0, 0.0, 0.0);
+    pub const ONE: Self = Self::new(1.0, 1.0, 1.0);
+    pub const X: Self = Self::new(1.0, 0.0, 0.0);
+    pub const Y: Self = Self::new(0.0, 1.0, 0.0);
+    pub const Z: Self = Self::new(0.0, 0.0, 1.0);
+    pub const NEG_X: Self = Self::new(-1.0, 0.0, 0.0);
+    pub const NEG_Y: Self = Self::new(0.0, -1.0, 0.0);
+    pub const NEG_Z: Self = Self::new(0.0, 0.0, -1.0);
+    pub const NAN: Self = Self::new(f64::NAN, f64::NAN, f64::NAN);
+    pub const INFINITY: Self = Self::new(f64::INFINITY, f64::INFINITY, f64::INFINITY);
+    pub const NEG_INFINITY: Self =
+        Self::new(f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY);
+    pub const MIN: Self = Self::new(f64::MIN, f64::MIN, f64::MIN);
+    pub const MAX: Self = Self::new(f64::MAX, f64::MAX, f64::MAX);
+
     #[inline]
     #[must_use]
-    pub fn new(x: f64, y: f64, z: f64) -> Self {
+    pub const fn new(x: f64, y: f64, z: f64) -> Self {
         Self(f64x4::from_array([x, y, z, 0.0]))
     }
 
+    /// Wraps the result of a SIMD operation, checking in debug builds that the padding lane
+    /// is still `+0.0`.
+    #[inline]
+    pub(crate) fn from_simd(lanes: f64x4) -> Self {
+        debug_assert!(
+            lanes[3].to_bits() == 0,
+            "Vector3 padding lane must be zero, found {}",
+            lanes[3]
+        );
+        Self(lanes)
+    }
+
+    /// Returns all four lanes, padding included, for kernels that transpose vectors into
+    /// per-lane registers.
+    #[inline]
+    pub(crate) const fn to_simd(self) -> f64x4 {
+        self.0
+    }
+
+    /// Splats `scalar` into the three component lanes and 1.0 into the padding lane, so that
+    /// multiplying or dividing by it leaves the padding at zero for any `scalar`.
+    #[inline]
+    fn scalar_lanes(scalar: f64) -> f64x4 {
+        f64x4::from_array([scalar, scalar, scalar, 1.0])
+    }
+
     #[inline]
     #[must_use]
-    pub fn zeros() -> Self {
-        Self(f64x4::default())
+    pub const fn zeros() -> Self {
+        Self::ZERO
     }
 
     #[inline]
     #[must_use]
-    pub fn ones() -> Self {
-        Self(f64x4::from_array([1.0, 1.0, 1.0, 0.0]))
+    pub const fn ones() -> Self {
+        Self::ONE
     }
 
     #[inline]
     #[must_use]
-    pub fn x(&self) -> f64 {
-        self.0[0]
+    pub const fn x(&self) -> f64 {
+        self.0.as_array()[0]
     }
 
     #[inline]
     #[must_use]
-    pub fn y(&self) -> f64 {
-        self.0[1]
+    pub const fn y(&self) -> f64 {
+        self.0.as_array()[1]
     }
 
     #[inline]
     #[must_use]
-    pub fn z(&self) -> f64 {
-        self.0[2]
+    pub const fn z(&self) -> f64 {
+        self.0.as_array()[2]
     }
 
     #[inline]
@@ -62,12 +141,115 @@ impl Vector3 {
         self.0[2] = z;
     }
 
+    #[inline]
+    #[must_use]
+    pub const fn from_array(array: [f64; 3]) -> Self {
+        Self::new(array[0], array[1], array[2])
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn to_array(self) -> [f64; 3] {
+        [self.x(), self.y(), self.z()]
+    }
+
+    /// Const-evaluable `self + rhs`. At runtime, prefer the operator.
+    #[inline]
+    #[must_use]
+    pub const fn const_add(self, rhs: Self) -> Self {
+        Self::new(self.x() + rhs.x(), self.y() + rhs.y(), self.z() + rhs.z())
+    }
+
+    /// Const-evaluable `self - rhs`. At runtime, prefer the operator.
+    #[inline]
+    #[must_use]
+    pub const fn const_sub(self, rhs: Self) -> Self {
+        Self::new(self.x() - rhs.x(), self.y() - rhs.y(), self.z() - rhs.z())
+    }
+
+    /// Const-evaluable `self * rhs`. At runtime, prefer the operator.
+    #[inline]
+    #[must_use]
+    pub const fn const_mul(self, rhs: f64) -> Self {
+        Self::new(self.x() * rhs, self.y() * rhs, self.z() * rhs)
+    }
+
+    /// Const-evaluable `self / rhs`. At runtime, prefer the operator.
+    #[inline]
+    #[must_use]
+    pub const fn const_div(self, rhs: f64) -> Self {
+        Self::new(self.x() / rhs, self.y() / rhs, self.z() / rhs)
+    }
+
+    /// Const-evaluable `-self`. At runtime, prefer the operator.
+    #[inline]
+    #[must_use]
+    pub const fn const_neg(self) -> Self {
+        Self::new(-self.x(), -self.y(), -self.z())
+    }
+
+    /// Const-evaluable [`dot`](Self::dot), bit-for-bit equal to it.
+    #[inline]
+    #[must_use]
+    pub const fn const_dot(self, rhs: Self) -> f64 {
+        // `dot` also adds the padding lanes' product, +0.0, which makes a zero sum positive.
+        self.x() * rhs.x() + self.y() * rhs.y() + self.z() * rhs.z() + 0.0
+    }
+
+    /// Const-evaluable [`cross`](Self::cross).
+    #[inline]
+    #[must_use]
+    pub const fn const_cross(self, rhs: Self) -> Self {
+        Self::new(
+            self.y() * rhs.z() - self.z() * rhs.y(),
+            self.z() * rhs.x() - self.x() * rhs.z(),
+            self.x() * rhs.y() - self.y() * rhs.x(),
+        )
+    }
+
+    /// Builds a vector from the first three elements of `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` has fewer than three elements.
+    #[inline]
+    #[must_use]
+    pub fn from_slice(slice: &[f64]) -> Self {
+        Self::new(slice[0], slice[1], slice[2])
+    }
+
+    /// Writes the components into the first three elements of `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` has fewer than three elements.
+    #[inline]
+    pub fn write_to_slice(self, slice: &mut [f64]) {
+        slice[..3].copy_from_slice(&self.0.as_array()[..3]);
+    }
+
+    #[inline]
+    pub fn iter(&self) -> core::array::IntoIter<f64, 3> {
+        self.to_array().into_iter()
+    }
+
     #[inline]
     #[must_use]
     pub fn dot(self, rhs: Self) -> f64 {
         (self.0 * rhs.0).reduce_sum()
     }
 
+    /// Dot product using fused multiply-adds, which round once per component instead of
+    /// twice. This is more accurate when the products nearly cancel, but not bit-for-bit equal
+    /// to [`dot`](Self::dot). Without hardware FMA (e.g. x86-64 builds without
+    /// `-C target-feature=+fma`) it falls back to a much slower software implementation.
+    #[inline]
+    #[must_use]
+    pub fn dot_fma(self, rhs: Self) -> f64 {
+        let (a, b) = (self.0, rhs.0);
+        math::mul_add(a[0], b[0], math::mul_add(a[1], b[1], a[2] * b[2]))
+    }
+
     #[inline]
     #[must_use]
     pub fn magnitude_squared(self) -> f64 {
@@ -77,7 +259,7 @@ impl Vector3 {
     #[inline]
     #[must_use]
     pub fn magnitude(self) -> f64 {
-        self.magnitude_squared().sqrt()
+        math::sqrt(self.magnitude_squared())
     }
 
     #[inline]
@@ -91,6 +273,139 @@ impl Vector3 {
         }
     }
 
+    /// Approximates `1 / magnitude()` from the CPU's reciprocal square root estimate, refined
+    /// by `steps` Newton-Raphson iterations. Each step roughly doubles the correct bits, and
+    /// the worst relative errors are:
+    ///
+    /// | `steps` | x86-64 (`rsqrtss`) | AArch64 (`frsqrte`) | other targets |
+    /// |---------|--------------------|---------------------|---------------|
+    /// | 0       | 3.7e-4             | 3.9e-3              | 1.8e-3        |
+    /// | 1       | 2.1e-7             | 2.3e-5              | 4.7e-6        |
+    /// | 2       | 1e-13              | 8e-10               | 3.3e-11       |
+    /// | 3       | 1e-15              | 1e-15               | 1e-15         |
+    ///
+    /// One step is enough for most graphics work. Zero, subnormal, infinite and NaN squared
+    /// magnitudes fall back to the exact `1.0 / magnitude()`, so a zero vector gives infinity.
+    ///
+    /// This is only faster where double-precision square root and division are slow. Recent
+    /// x86-64 cores run both quickly, and there the `normalize_all` benchmark in
+    /// `benches/batch_bench.rs` shows `normalize_fast(1)` slower than
+    /// [`normalize`](Self::normalize); measure on your target before switching.
+    #[inline]
+    #[must_use]
+    pub fn magnitude_recip_fast(self, steps: u32) -> f64 {
+        math::rsqrt_fast(self.magnitude_squared(), steps)
+    }
+
+    /// Normalizes the vector with [`magnitude_recip_fast`](Self::magnitude_recip_fast), trading
+    /// accuracy for speed. Zero vectors stay zero, as with [`normalize`](Self::normalize).
+    #[inline]
+    #[must_use]
+    pub fn normalize_fast(self, steps: u32) -> Self {
+        let mag_squared = self.magnitude_squared();
+        if mag_squared == 0.0 {
+            Self::zeros()
+        } else {
+            self * math::rsqrt_fast(mag_squared, steps)
+        }
+    }
+
+    /// Normalizes the vector, failing instead of returning zeros or NaNs.
+    ///
+    /// Returns [`FastVecError::NonFinite`] if a component is infinite or NaN, and
+    /// [`FastVecError::ZeroLength`] if every component is zero. Vectors whose squared length
+    /// overflows or underflows are scaled first, so any other finite vector succeeds.
+    #[inline]
+    pub fn try_normalize(self) -> core::result::Result<Self, FastVecError> {
+        self.normalize_scaled().map(|(normalized, _, _)| normalized)
+    }
+
+    /// Like [`try_normalize`](Self::try_normalize), but also returns the original length.
+    /// Returns [`FastVecError::NonFinite`] if the length itself overflows.
+    #[inline]
+    pub fn normalize_and_length(self) -> core::result::Result<(Self, f64), FastVecError> {
+        let (normalized, mag, scale) = self.normalize_scaled()?;
+        let length = mag * scale;
+        if length.is_finite() {
+            Ok((normalized, length))
+        } else {
+            Err(FastVecError::NonFinite)
+        }
+    }
+
+    /// Returns the normalized vector and its length as `mag * scale`. The length is only
+    /// split when the squared length is out of range, in which case the vector is divided by
+    /// its largest absolute component before squaring.
+    #[inline]
+    fn normalize_scaled(self) -> core::result::Result<(Self, f64, f64), FastVecError> {
+        let mag_squared = self.magnitude_squared();
+        if mag_squared.is_normal() && mag_squared.is_finite() {
+            let mag = math::sqrt(mag_squared);
+            return Ok((self * (1.0 / mag), mag, 1.0));
+        }
+        if !self.is_finite() {
+            return Err(FastVecError::NonFinite);
+        }
+        let scale = self.iter().fold(0.0, |max, c| f64::max(max, c.abs()));
+        if scale == 0.0 {
+            return Err(FastVecError::ZeroLength);
+        }
+        let scaled = self.div_exact(scale);
+        let mag = scaled.magnitude();
+        Ok((scaled * (1.0 / mag), mag, scale))
+    }
+
+    /// Normalizes the vector, returning `fallback` where [`try_normalize`](Self::try_normalize)
+    /// would fail.
+    #[inline]
+    #[must_use]
+    pub fn normalize_or(self, fallback: Self) -> Self {
+        self.try_normalize().unwrap_or(fallback)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_normalized(self, tolerance: f64) -> bool {
+        (self.magnitude() - 1.0).abs() <= tolerance
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_finite(self) -> bool {
+        self.iter().all(f64::is_finite)
+    }
+
+    /// Divides each component by `rhs` with IEEE division, so the result is correctly
+    /// rounded. This is what `/` and `/=` do unless the `fast-math` feature is enabled.
+    #[inline]
+    #[must_use]
+    pub fn div_exact(self, rhs: f64) -> Self {
+        Self::from_simd(self.0 / Self::scalar_lanes(rhs))
+    }
+
+    /// Multiplies by `1.0 / rhs`, which is faster than [`div_exact`](Self::div_exact) but may
+    /// differ from it by one ulp per component. This is what `/` and `/=` do when the
+    /// `fast-math` feature is enabled.
+    #[inline]
+    #[must_use]
+    pub fn div_fast(self, rhs: f64) -> Self {
+        Self::from_simd(self.0 * Self::scalar_lanes(1.0 / rhs))
+    }
+
+    /// Divides by `rhs`, failing if it is zero or if the result is not finite.
+    #[inline]
+    pub fn checked_div(self, rhs: f64) -> core::result::Result<Self, FastVecError> {
+        if rhs == 0.0 {
+            return Err(FastVecError::DivisionByZero);
+        }
+        let result = self / rhs;
+        if result.is_finite() {
+            Ok(result)
+        } else {
+            Err(FastVecError::NonFinite)
+        }
+    }
+
     #[inline]
     #[must_use]
     pub fn cross(self, rhs: Self) -> Self {
@@ -102,7 +417,83 @@ impl Vector3 {
             a[0] * b[1] - a[1] * b[0],
             0.0,
         ]);
-        Self(result)
+        Self::from_simd(result)
+    }
+
+    /// Cross product with each component computed by Kahan's difference of products, accurate
+    /// to 1.5 ulps even when the two products nearly cancel. See [`dot_fma`](Self::dot_fma)
+    /// about performance.
+    #[inline]
+    #[must_use]
+    pub fn cross_fma(self, rhs: Self) -> Self {
+        let (a, b) = (self.0, rhs.0);
+        Self::new(
+            math::difference_of_products(a[1], b[2], a[2], b[1]),
+            math::difference_of_products(a[2], b[0], a[0], b[2]),
+            math::difference_of_products(a[0], b[1], a[1], b[0]),
+        )
+    }
+
+    /// Returns a non-zero vector perpendicular to `self`, which need not be normalized.
+    /// The result is not normalized either.
+    #[inline]
+    #[must_use]
+    pub fn any_orthogonal(self) -> Self {
+        if self.x().abs() > self.z().abs() {
+            Self::new(-self.y(), self.x(), 0.0)
+        } else {
+            Self::new(0.0, -self.z(), self.y())
+        }
+    }
+
+    /// Returns two unit vectors that, together with `self`, form a right-handed orthonormal
+    /// basis, using the branchless construction of Duff et al. (2017). `self` must be
+    /// normalized.
+    #[inline]
+    #[must_use]
+    pub fn orthonormal_basis(self) -> (Self, Self) {
+        let (x, y, z) = (self.x(), self.y(), self.z());
+        let sign = 1.0f64.copysign(z);
+        let a = -1.0 / (sign + z);
+        let b = x * y * a;
+        (
+            Self::new(1.0 + sign * x * x * a, sign * b, -sign * x),
+            Self::new(b, sign + y * y * a, -y),
+        )
+    }
+
+    /// Orthonormalizes `vectors` in order with modified Gram-Schmidt.
+    ///
+    /// Returns [`FastVecError::LinearlyDependent`] if a vector is (numerically) in the span of
+    /// the ones before it, which includes zero vectors and any fourth vector, and
+    /// [`FastVecError::NonFinite`] if an input is not finite.
+    pub fn gram_schmidt<const N: usize>(
+        vectors: [Self; N],
+    ) -> core::result::Result<[Self; N], FastVecError> {
+        const RELATIVE_TOLERANCE: f64 = 1e-10;
+
+        if !vectors.iter().all(|v| v.is_finite()) {
+            return Err(FastVecError::NonFinite);
+        }
+        let mut basis = vectors;
+        for i in 0..N {
+            // Work on the input divided by `scale`, whose length `original` neither overflows
+            // nor underflows.
+            let (_, original, scale) =
+                basis[i].normalize_scaled().map_err(|error| match error {
+                    FastVecError::ZeroLength => FastVecError::LinearlyDependent,
+                    error => error,
+                })?;
+            let mut v = basis[i].div_exact(scale);
+            for e in &basis[..i] {
+                v -= *e * v.dot(*e);
+            }
+            if v.magnitude() <= original * RELATIVE_TOLERANCE {
+                return Err(FastVecError::LinearlyDependent);
+            }
+            basis[i] = v.try_normalize()?;
+        }
+        Ok(basis)
     }
 
     #[inline]
@@ -116,36 +507,152 @@ impl Vector3 {
     pub fn distance_squared(self, rhs: Self) -> f64 {
         (self - rhs).magnitude_squared()
     }
+
+    /// Distance computed with [`dot_fma`](Self::dot_fma).
+    #[inline]
+    #[must_use]
+    pub fn distance_fma(self, rhs: Self) -> f64 {
+        let d = self - rhs;
+        math::sqrt(d.dot_fma(d))
+    }
+
+    /// Linear interpolation, `self * (1 - t) + rhs * t`. For finite inputs it returns
+    /// exactly `self` at `t = 0` and `rhs` at `t = 1`.
+    #[inline]
+    #[must_use]
+    pub fn lerp(self, rhs: Self, t: f64) -> Self {
+        self * (1.0 - t) + rhs * t
+    }
+
+    /// [`lerp`](Self::lerp) computed as `t * rhs + (self - t * self)` with two fused
+    /// multiply-adds per component, so each component rounds twice instead of four times.
+    /// Also exact at `t = 0` and `t = 1`.
+    #[inline]
+    #[must_use]
+    pub fn lerp_fma(self, rhs: Self, t: f64) -> Self {
+        let lerp = |a: f64, b: f64| math::mul_add(t, b, math::mul_add(-t, a, a));
+        Self::new(
+            lerp(self.x(), rhs.x()),
+            lerp(self.y(), rhs.y()),
+            lerp(self.z(), rhs.z()),
+        )
+    }
+
+    /// Returns the component-wise minimum. A NaN component is ignored in favour of the other.
+    #[inline]
+    #[must_use]
+    pub fn min(self, rhs: Self) -> Self {
+        Self::from_simd(self.0.simd_min(rhs.0))
+    }
+
+    /// Returns the component-wise maximum. A NaN component is ignored in favour of the other.
+    #[inline]
+    #[must_use]
+    pub fn max(self, rhs: Self) -> Self {
+        Self::from_simd(self.0.simd_max(rhs.0))
+    }
+
+    /// Returns `true` if every component differs from `rhs` by at most `epsilon`.
+    #[inline]
+    #[must_use]
+    pub fn abs_diff_eq(self, rhs: Self, epsilon: f64) -> bool {
+        self.iter()
+            .zip(rhs.iter())
+            .all(|(a, b)| approx::abs_diff_eq(a, b, epsilon))
+    }
+
+    /// Returns `true` if every component differs from `rhs` by at most `epsilon`, or by at
+    /// most `max_relative` times the larger of the two magnitudes.
+    #[inline]
+    #[must_use]
+    pub fn relative_eq(self, rhs: Self, epsilon: f64, max_relative: f64) -> bool {
+        self.iter()
+            .zip(rhs.iter())
+            .all(|(a, b)| approx::relative_eq(a, b, epsilon, max_relative))
+    }
+
+    /// Returns `true` if every component differs from `rhs` by at most `epsilon`, or is at
+    /// most `max_ulps` representable values away with the same sign.
+    #[inline]
+    #[must_use]
+    pub fn ulps_eq(self, rhs: Self, epsilon: f64, max_ulps: u64) -> bool {
+        self.iter()
+            .zip(rhs.iter())
+            .all(|(a, b)| approx::ulps_eq(a, b, epsilon, max_ulps))
+    }
+
+    /// Returns the arithmetic mean of the vectors, or `None` if the iterator is empty.
+    #[must_use]
+    pub fn mean<I>(iter: I) -> Option<Self>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Self>,
+    {
+        let mut sum = Self::zeros();
+        let mut count = 0usize;
+        for v in iter {
+            sum += *v.borrow();
+            count += 1;
+        }
+        (count > 0).then(|| sum / count as f64)
+    }
+
+    /// Alias of [`Vector3::mean`] for when the vectors represent points.
+    #[inline]
+    #[must_use]
+    pub fn centroid<I>(iter: I) -> Option<Self>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Self>,
+    {
+        Self::mean(iter)
+    }
+
+    /// Returns the mean of the vectors weighted by the paired scalars, or `None` if the
+    /// weights sum to zero (which includes the empty iterator).
+    #[must_use]
+    pub fn weighted_mean<I>(iter: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = (Self, f64)>,
+    {
+        let mut sum = Self::zeros();
+        let mut total_weight = 0.0;
+        for (v, weight) in iter {
+            sum += v * weight;
+            total_weight += weight;
+        }
+        (total_weight != 0.0).then(|| sum / total_weight)
+    }
 }
 
-impl std::ops::Add for Vector3 {
+impl core::ops::Add for Vector3 {
     type Output = Vector3;
 
     #[inline]
     fn add(self, rhs: Self) -> Self::Output {
-        Self(self.0 + rhs.0)
+        Self::from_simd(self.0 + rhs.0)
     }
 }
 
-impl std::ops::Sub for Vector3 {
+impl core::ops::Sub for Vector3 {
     type Output = Vector3;
 
     #[inline]
     fn sub(self, rhs: Self) -> Self::Output {
-        Self(self.0 - rhs.0)
+        Self::from_simd(self.0 - rhs.0)
     }
 }
 
-impl std::ops::Mul<f64> for Vector3 {
+impl core::ops::Mul<f64> for Vector3 {
     type Output = Vector3;
 
     #[inline]
     fn mul(self, rhs: f64) -> Self::Output {
-        Self(self.0 * f64x4::splat(rhs))
+        Self::from_simd(self.0 * Self::scalar_lanes(rhs))
     }
 }
 
-impl std::ops::Mul<Vector3> for f64 {
+impl core::ops::Mul<Vector3> for f64 {
     type Output = Vector3;
 
     #[inline]
@@ -154,49 +661,141 @@ impl std::ops::Mul<Vector3> for f64 {
     }
 }
 
-impl std::ops::Div<f64> for Vector3 {
+impl core::ops::Div<f64> for Vector3 {
     type Output = Vector3;
 
+    /// Uses [`div_exact`](Vector3::div_exact), or [`div_fast`](Vector3::div_fast) with the
+    /// `fast-math` feature.
     #[inline]
     fn div(self, rhs: f64) -> Self::Output {
-        Self(self.0 * f64x4::splat(1.0 / rhs))
+        if cfg!(feature = "fast-math") {
+            self.div_fast(rhs)
+        } else {
+            self.div_exact(rhs)
+        }
     }
 }
 
-impl std::ops::Neg for Vector3 {
+impl core::ops::Neg for Vector3 {
     type Output = Vector3;
 
     #[inline]
     fn neg(self) -> Self::Output {
-        Self(-self.0)
+        // Negating the padding lane too would make it -0.0, which shows up in the sign of a
+        // zero `dot`.
+        Self::from_simd(simd_swizzle!(-self.0, self.0, [0, 1, 2, 7]))
     }
 }
 
-impl std::ops::AddAssign for Vector3 {
+impl core::ops::AddAssign for Vector3 {
     #[inline]
     fn add_assign(&mut self, rhs: Self) {
-        self.0 += rhs.0;
+        *self = *self + rhs;
     }
 }
 
-impl std::ops::SubAssign for Vector3 {
+impl core::ops::SubAssign for Vector3 {
     #[inline]
     fn sub_assign(&mut self, rhs: Self) {
-        self.0 -= rhs.0;
+        *self = *self - rhs;
     }
 }
 
-impl std::ops::MulAssign<f64> for Vector3 {
+impl core::ops::MulAssign<f64> for Vector3 {
     #[inline]
     fn mul_assign(&mut self, rhs: f64) {
-        self.0 *= f64x4::splat(rhs);
+        *self = *self * rhs;
     }
 }
 
-impl std::ops::DivAssign<f64> for Vector3 {
+impl core::ops::DivAssign<f64> for Vector3 {
     #[inline]
     fn div_assign(&mut self, rhs: f64) {
-        self.0 /= f64x4::splat(rhs);
+        *self = *self / rhs;
+    }
+}
+
+impl core::ops::Index<usize> for Vector3 {
+    type Output = f64;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0.as_array()[..3][index]
+    }
+}
+
+impl core::ops::IndexMut<usize> for Vector3 {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.0.as_mut_array()[..3][index]
+    }
+}
+
+impl From<[f64; 3]> for Vector3 {
+    #[inline]
+    fn from(array: [f64; 3]) -> Self {
+        Self::from_array(array)
+    }
+}
+
+/// Builds a vector from a slice of exactly three components.
+impl TryFrom<&[f64]> for Vector3 {
+    type Error = ParseVectorError;
+
+    #[inline]
+    fn try_from(slice: &[f64]) -> core::result::Result<Self, Self::Error> {
+        let array =
+            <[f64; 3]>::try_from(slice).map_err(|_| ParseVectorError::WrongComponentCount {
+                expected: 3,
+                found: slice.len(),
+            })?;
+        Ok(Self::from_array(array))
+    }
+}
+
+impl From<(f64, f64, f64)> for Vector3 {
+    #[inline]
+    fn from((x, y, z): (f64, f64, f64)) -> Self {
+        Self::new(x, y, z)
+    }
+}
+
+impl From<Vector3> for [f64; 3] {
+    #[inline]
+    fn from(v: Vector3) -> Self {
+        v.to_array()
+    }
+}
+
+impl From<Vector3> for (f64, f64, f64) {
+    #[inline]
+    fn from(v: Vector3) -> Self {
+        (v.x(), v.y(), v.z())
+    }
+}
+
+impl core::iter::Sum for Vector3 {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zeros(), |acc, v| acc + v)
+    }
+}
+
+impl<'a> core::iter::Sum<&'a Vector3> for Vector3 {
+    fn sum<I: Iterator<Item = &'a Vector3>>(iter: I) -> Self {
+        iter.fold(Self::zeros(), |acc, v| acc + *v)
+    }
+}
+
+/// Multiplies component-wise, starting from [`ONE`](Self::ONE).
+impl core::iter::Product for Vector3 {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |acc, v| Self::from_simd(acc.0 * v.0))
+    }
+}
+
+impl<'a> core::iter::Product<&'a Vector3> for Vector3 {
+    fn product<I: Iterator<Item = &'a Vector3>>(iter: I) -> Self {
+        iter.copied().product()
     }
 }
 
@@ -219,6 +818,8 @@ impl Copy for Vector3 {}
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::assert_vec_approx_eq;
+    use proptest::prelude::*;
 
     #[test]
     fn test_new() {
@@ -325,9 +926,7 @@ mod tests {
         let normalized = a.normalize();
         let mag = normalized.magnitude();
         assert!((mag - 1.0).abs() < 1e-10);
-        assert!((normalized.x() - 0.6).abs() < 1e-10);
-        assert!((normalized.y() - 0.8).abs() < 1e-10);
-        assert_eq!(normalized.z(), 0.0);
+        crate::assert_vec_approx_eq!(normalized, Vector3::new(0.6, 0.8, 0.0));
     }
 
     #[test]
@@ -370,6 +969,153 @@ mod tests {
         assert_eq!(dist_sq, 25.0);
     }
 
+    #[test]
+    fn test_min_max() {
+        let a = Vector3::new(1.0, -2.0, f64::NAN);
+        let b = Vector3::new(0.5, 3.0, 4.0);
+        assert_eq!(a.min(b), Vector3::new(0.5, -2.0, 4.0));
+        assert_eq!(a.max(b), Vector3::new(1.0, 3.0, 4.0));
+    }
+
+    #[test]
+    fn test_dot_fma_cancellation() {
+        // (1 + e)(1 - e) - 1 = -e^2, which is below the rounding error of the first product.
+        let e = 2f64.powi(-30);
+        let a = Vector3::new(1.0 + e, 1.0, 0.0);
+        let b = Vector3::new(1.0 - e, -1.0, 0.0);
+        assert_eq!(a.dot(b), 0.0);
+        assert_eq!(a.dot_fma(b), -e * e);
+        assert_eq!(Vector3::new(1.0, 2.0, 3.0).dot_fma(Vector3::ONE), 6.0);
+    }
+
+    #[test]
+    fn test_cross_fma_accuracy() {
+        let e = 2f64.powi(-30);
+        let a = Vector3::new(0.0, 1.0 + e, 1.0);
+        let b = Vector3::new(0.0, 1.0, 1.0 - e);
+        assert_eq!(a.cross(b).x(), 0.0);
+        assert_eq!(a.cross_fma(b).x(), -e * e);
+
+        // Nearly parallel integer vectors: the exact result fits in an i128, while the 60-bit
+        // products round in f64.
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let mut next = || {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1);
+            (state >> 34) as i64 | 1 << 29
+        };
+        let (mut fma_error, mut plain_error) = (0i128, 0i128);
+        for _ in 0..1000 {
+            let a = [next(), next(), next()];
+            let b = a.map(|c| c + next() % 8 - 4);
+            let exact = |i: usize, j: usize| {
+                i128::from(a[i]) * i128::from(b[j]) - i128::from(a[j]) * i128::from(b[i])
+            };
+            let to_vector = |c: [i64; 3]| Vector3::new(c[0] as f64, c[1] as f64, c[2] as f64);
+            let (va, vb) = (to_vector(a), to_vector(b));
+            let (fma, plain) = (va.cross_fma(vb), va.cross(vb));
+            for (axis, (i, j)) in [(1, 2), (2, 0), (0, 1)].into_iter().enumerate() {
+                let exact = exact(i, j);
+                let ulp = (exact as f64).abs() * f64::EPSILON;
+                let error = (fma[axis] as i128 - exact).abs();
+                assert!(
+                    error as f64 <= 1.5 * ulp.max(f64::MIN_POSITIVE),
+                    "{error} > 1.5 ulp"
+                );
+                fma_error += error;
+                plain_error += (plain[axis] as i128 - exact).abs();
+            }
+        }
+        assert!(
+            fma_error * 100 < plain_error,
+            "{fma_error} vs {plain_error}"
+        );
+    }
+
+    #[test]
+    fn test_distance_fma() {
+        let a = Vector3::new(1.0, 2.0, 3.0);
+        assert_eq!(a.distance_fma(Vector3::new(4.0, 6.0, 3.0)), 5.0);
+        assert_eq!(a.distance_fma(a), 0.0);
+    }
+
+    /// Reads this target's column of the error table in the `magnitude_recip_fast` docs, so
+    /// the documented bounds are the ones tested.
+    fn documented_rsqrt_error() -> [f64; 4] {
+        let column = if cfg!(target_arch = "x86_64") {
+            1
+        } else if cfg!(target_arch = "aarch64") {
+            2
+        } else {
+            3
+        };
+        let mut bounds = [0.0; 4];
+        let rows = include_str!("vec3.rs")
+            .lines()
+            .map(str::trim_start)
+            .filter_map(|line| line.strip_prefix("/// | "))
+            .filter(|row| row.starts_with(|c: char| c.is_ascii_digit()));
+        for (steps, (bound, row)) in bounds.iter_mut().zip(rows).enumerate() {
+            let cells: Vec<&str> = row.split('|').map(str::trim).collect();
+            assert_eq!(cells[0], steps.to_string());
+            *bound = cells[column].parse().unwrap();
+        }
+        assert!(bounds.iter().all(|&bound| bound > 0.0), "{bounds:?}");
+        bounds
+    }
+
+    #[test]
+    fn test_magnitude_recip_fast_error() {
+        // Mantissas across [1, 4) at exponents spanning the whole f64 range.
+        let mut max_error = [0.0f64; 4];
+        for scale in (-150..=150).step_by(15).map(|e| 10f64.powi(e)) {
+            for i in 0..2000 {
+                let t = i as f64 / 2000.0;
+                let v = Vector3::new(1.0 + t, (t * 7.0).sin(), 0.5 - t) * scale;
+                let exact = 1.0 / v.magnitude();
+                for (steps, max) in max_error.iter_mut().enumerate() {
+                    let fast = v.magnitude_recip_fast(steps as u32);
+                    *max = max.max(((fast - exact) / exact).abs());
+                }
+            }
+        }
+        for (steps, (error, bound)) in max_error.iter().zip(documented_rsqrt_error()).enumerate() {
+            assert!(error <= &bound, "{steps} steps: {error:e} > {bound:e}");
+        }
+        assert!(max_error[1] > 0.0, "the estimate should not be exact");
+    }
+
+    #[test]
+    fn test_normalize_fast() {
+        let v = Vector3::new(3.0, -4.0, 12.0);
+        assert!(v.normalize_fast(1).abs_diff_eq(v.normalize(), 3e-7));
+        assert!(v.normalize_fast(2).abs_diff_eq(v.normalize(), 1e-12));
+        assert!((v.normalize_fast(1).magnitude() - 1.0).abs() < 3e-7);
+        assert_eq!(Vector3::ZERO.normalize_fast(1), Vector3::ZERO);
+        assert_eq!(Vector3::ZERO.magnitude_recip_fast(1), f64::INFINITY);
+        // Subnormal and overflowing squared magnitudes take the exact path.
+        let tiny = Vector3::new(1e-160, 0.0, 0.0);
+        assert_eq!(tiny.normalize_fast(0), tiny.normalize());
+        assert_eq!(Vector3::MAX.magnitude_recip_fast(1), 0.0);
+        assert!(Vector3::NAN.normalize_fast(1).x().is_nan());
+    }
+
+    #[test]
+    fn test_lerp() {
+        let a = Vector3::new(1.0, -2.0, 0.1);
+        let b = Vector3::new(3.0, 5.0, 0.7);
+        for lerp in [Vector3::lerp, Vector3::lerp_fma] {
+            assert_eq!(lerp(a, b, 0.0), a);
+            assert_eq!(lerp(a, b, 1.0), b);
+            assert_vec_approx_eq!(lerp(a, b, 0.5), Vector3::new(2.0, 1.5, 0.4));
+            assert_vec_approx_eq!(lerp(a, b, 2.0), Vector3::new(5.0, 12.0, 1.3));
+        }
+        // The padding lane stays zero (checked in debug builds) even for a non-finite t.
+        assert!(a.lerp(b, f64::INFINITY).x().is_nan());
+        assert!(a.lerp_fma(b, f64::NAN).x().is_nan());
+    }
+
     #[test]
     fn test_add_assign() {
         let mut a = Vector3::new(1.0, 2.0, 3.0);
@@ -400,6 +1146,31 @@ mod tests {
         assert_eq!(a, Vector3::new(1.0, 2.0, 3.0));
     }
 
+    #[test]
+    fn test_div_exact_and_fast() {
+        let a = Vector3::new(5.0, 7.0, 1.0);
+        assert_eq!(
+            a.div_exact(3.0),
+            Vector3::new(5.0 / 3.0, 7.0 / 3.0, 1.0 / 3.0)
+        );
+        assert_eq!(
+            a.div_fast(3.0),
+            Vector3::new(5.0 * (1.0 / 3.0), 7.0 * (1.0 / 3.0), 1.0 / 3.0)
+        );
+        assert_ne!(a.div_exact(3.0), a.div_fast(3.0));
+    }
+
+    #[test]
+    fn test_div_operator_policy() {
+        let a = Vector3::new(5.0, 7.0, 1.0);
+        let expected = if cfg!(feature = "fast-math") {
+            a.div_fast(3.0)
+        } else {
+            a.div_exact(3.0)
+        };
+        assert_eq!(a / 3.0, expected);
+    }
+
     #[test]
     fn test_equality() {
         let a = Vector3::new(1.0, 2.0, 3.0);
@@ -417,4 +1188,653 @@ mod tests {
         assert_eq!(a, b);
         assert_eq!(a, c);
     }
+
+    #[test]
+    fn test_index() {
+        let mut v = Vector3::new(1.0, 2.0, 3.0);
+        assert_eq!(v[0], 1.0);
+        assert_eq!(v[1], 2.0);
+        assert_eq!(v[2], 3.0);
+        v[2] = 5.0;
+        assert_eq!(v, Vector3::new(1.0, 2.0, 5.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_padding_lane() {
+        let v = Vector3::new(1.0, 2.0, 3.0);
+        let _ = v[3];
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_mut_padding_lane() {
+        let mut v = Vector3::new(1.0, 2.0, 3.0);
+        v[3] = 1.0;
+    }
+
+    #[test]
+    fn test_array_conversions() {
+        let v = Vector3::from_array([1.0, 2.0, 3.0]);
+        assert_eq!(v, Vector3::new(1.0, 2.0, 3.0));
+        assert_eq!(v.to_array(), [1.0, 2.0, 3.0]);
+        assert_eq!(Vector3::from([4.0, 5.0, 6.0]), Vector3::new(4.0, 5.0, 6.0));
+        let array: [f64; 3] = v.into();
+        assert_eq!(array, [1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn test_tuple_conversions() {
+        let v = Vector3::from((1.0, 2.0, 3.0));
+        assert_eq!(v, Vector3::new(1.0, 2.0, 3.0));
+        let tuple: (f64, f64, f64) = v.into();
+        assert_eq!(tuple, (1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn test_slice_conversions() {
+        let v = Vector3::from_slice(&[1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(v, Vector3::new(1.0, 2.0, 3.0));
+        let mut out = [0.0; 4];
+        v.write_to_slice(&mut out);
+        assert_eq!(out, [1.0, 2.0, 3.0, 0.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_slice_too_short() {
+        let _ = Vector3::from_slice(&[1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_iter() {
+        let v = Vector3::new(1.0, 2.0, 3.0);
+        let components: Vec<f64> = v.iter().collect();
+        assert_eq!(components, vec![1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn test_display() {
+        let v = Vector3::new(1.0, -2.5, 0.125);
+        assert_eq!(format!("{v}"), "(1, -2.5, 0.125)");
+        assert_eq!(format!("{v:.3}"), "(1.000, -2.500, 0.125)");
+        assert_eq!(format!("{v:5.1}"), "(  1.0,  -2.5,   0.1)");
+    }
+
+    #[test]
+    fn test_exp() {
+        let v = Vector3::new(1.0, -2.5, 0.125);
+        assert_eq!(format!("{v:e}"), "(1e0, -2.5e0, 1.25e-1)");
+        assert_eq!(format!("{v:E}"), "(1E0, -2.5E0, 1.25E-1)");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            "(1, -2.5, 0.125)".parse::<Vector3>(),
+            Ok(Vector3::new(1.0, -2.5, 0.125))
+        );
+        assert_eq!(
+            "[1, 2, 3]".parse::<Vector3>(),
+            Ok(Vector3::new(1.0, 2.0, 3.0))
+        );
+        assert!("(1, 2)".parse::<Vector3>().is_err());
+    }
+
+    #[test]
+    fn test_product() {
+        let vectors = [Vector3::new(1.0, 2.0, 3.0), Vector3::new(4.0, -5.0, 0.5)];
+        assert_eq!(
+            vectors.iter().product::<Vector3>(),
+            Vector3::new(4.0, -10.0, 1.5)
+        );
+        assert_eq!(
+            vectors.into_iter().product::<Vector3>(),
+            Vector3::new(4.0, -10.0, 1.5)
+        );
+        assert_eq!(
+            core::iter::empty::<Vector3>().product::<Vector3>(),
+            Vector3::ONE
+        );
+    }
+
+    #[test]
+    fn test_try_from_slice() {
+        let components = [2.0, -3.0, 0.5];
+        assert_eq!(
+            Vector3::try_from(&components[..]),
+            Ok(Vector3::from_array(components))
+        );
+        for slice in [&[1.0][..], &[1.0; 4]] {
+            assert_eq!(
+                Vector3::try_from(slice),
+                Err(ParseVectorError::WrongComponentCount {
+                    expected: 3,
+                    found: slice.len()
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn test_sum() {
+        let points = vec![
+            Vector3::new(1.0, 2.0, 3.0),
+            Vector3::new(4.0, 5.0, 6.0),
+            Vector3::new(7.0, 8.0, 9.0),
+        ];
+        assert_eq!(
+            points.iter().sum::<Vector3>(),
+            Vector3::new(12.0, 15.0, 18.0)
+        );
+        assert_eq!(
+            points.into_iter().sum::<Vector3>(),
+            Vector3::new(12.0, 15.0, 18.0)
+        );
+        assert_eq!(
+            Vec::<Vector3>::new().into_iter().sum::<Vector3>(),
+            Vector3::zeros()
+        );
+    }
+
+    #[test]
+    fn test_mean() {
+        let points = vec![
+            Vector3::new(1.0, 2.0, 3.0),
+            Vector3::new(4.0, 5.0, 6.0),
+            Vector3::new(7.0, 8.0, 9.0),
+        ];
+        assert_eq!(Vector3::mean(&points), Some(Vector3::new(4.0, 5.0, 6.0)));
+        assert_eq!(Vector3::centroid(points), Some(Vector3::new(4.0, 5.0, 6.0)));
+        assert_eq!(Vector3::mean(Vec::<Vector3>::new()), None);
+    }
+
+    #[test]
+    fn test_weighted_mean() {
+        let weighted = [
+            (Vector3::new(0.0, 0.0, 0.0), 1.0),
+            (Vector3::new(4.0, 8.0, 12.0), 3.0),
+        ];
+        assert_eq!(
+            Vector3::weighted_mean(weighted),
+            Some(Vector3::new(3.0, 6.0, 9.0))
+        );
+        assert_eq!(
+            Vector3::weighted_mean([(Vector3::new(4.0, 8.0, 12.0), 0.0)]),
+            None
+        );
+        assert_eq!(Vector3::weighted_mean([]), None);
+    }
+
+    #[test]
+    fn test_abs_diff_eq() {
+        assert!(
+            Vector3::new(1.0, 2.0, 3.0).abs_diff_eq(Vector3::new(1.0, 2.0 + 1e-12, 3.0), 1e-10)
+        );
+        assert!(!Vector3::new(1.0, 2.0, 3.0).abs_diff_eq(Vector3::new(1.0, 2.0, 3.1), 1e-10));
+        assert!(Vector3::INFINITY.abs_diff_eq(Vector3::INFINITY, 1e-10));
+        assert!(Vector3::NEG_INFINITY.ulps_eq(Vector3::NEG_INFINITY, 0.0, 0));
+        assert!(!Vector3::INFINITY.abs_diff_eq(Vector3::NEG_INFINITY, 1e-10));
+        assert!(!Vector3::NAN.abs_diff_eq(Vector3::NAN, 1e-10));
+    }
+
+    #[test]
+    fn test_relative_eq() {
+        assert!(Vector3::new(1.0, 1e10, 1.0).relative_eq(
+            Vector3::new(1.0, 1e10 + 1.0, 1.0),
+            0.0,
+            1e-9
+        ));
+        assert!(!Vector3::new(1.0, 2.0, 3.0).relative_eq(Vector3::new(1.0, 2.0, 3.1), 0.0, 1e-9));
+    }
+
+    #[test]
+    fn test_ulps_eq() {
+        assert!(Vector3::new(1.0, 2.0, 3.0).ulps_eq(
+            Vector3::new(1.0, 2.0, f64::from_bits(3.0f64.to_bits() + 3)),
+            0.0,
+            3
+        ));
+        assert!(!Vector3::new(1.0, 2.0, 3.0).ulps_eq(
+            Vector3::new(1.0, 2.0, f64::from_bits(3.0f64.to_bits() + 3)),
+            0.0,
+            2
+        ));
+    }
+
+    #[test]
+    fn test_assert_vec_eq_macro() {
+        crate::assert_vec_eq!(Vector3::new(1.0, 2.0, 3.0), Vector3::new(1.0, 2.0, 3.0));
+        crate::assert_vec_approx_eq!(
+            Vector3::new(1.0, 2.0, 3.0),
+            Vector3::new(1.0, 2.0, 3.1),
+            0.2
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "error: [")]
+    fn test_assert_vec_approx_eq_macro_failure() {
+        crate::assert_vec_approx_eq!(Vector3::new(1.0, 2.0, 3.0), Vector3::new(1.0, 2.0, 3.1));
+    }
+
+    #[test]
+    fn test_try_normalize() {
+        crate::assert_vec_approx_eq!(
+            Vector3::new(3.0, 4.0, 0.0).try_normalize().unwrap(),
+            Vector3::new(0.6, 0.8, 0.0)
+        );
+        assert_eq!(
+            Vector3::zeros().try_normalize(),
+            Err(FastVecError::ZeroLength)
+        );
+        assert_eq!(
+            Vector3::new(1.0, f64::NAN, 1.0).try_normalize(),
+            Err(FastVecError::NonFinite)
+        );
+        assert_eq!(
+            Vector3::new(1.0, 1.0, f64::NEG_INFINITY).try_normalize(),
+            Err(FastVecError::NonFinite)
+        );
+        // Squared lengths that overflow or underflow are still valid directions.
+        assert_eq!(
+            Vector3::new(1e200, 0.0, 0.0).try_normalize(),
+            Ok(Vector3::X)
+        );
+        assert_eq!(
+            Vector3::new(1e-200, 0.0, 0.0).try_normalize(),
+            Ok(Vector3::X)
+        );
+        assert_eq!(
+            Vector3::new(0.0, -5e-324, 0.0).try_normalize(),
+            Ok(-Vector3::Y)
+        );
+        crate::assert_vec_approx_eq!(
+            Vector3::new(1e200, 1e200, 1e200).try_normalize().unwrap(),
+            Vector3::ONE * (1.0 / 3f64.sqrt())
+        );
+    }
+
+    #[test]
+    fn test_normalize_and_length() {
+        let (normalized, length) = Vector3::new(3.0, 4.0, 0.0).normalize_and_length().unwrap();
+        crate::assert_vec_approx_eq!(normalized, Vector3::new(0.6, 0.8, 0.0));
+        assert_eq!(length, 5.0);
+        assert_eq!(
+            Vector3::zeros().normalize_and_length(),
+            Err(FastVecError::ZeroLength)
+        );
+        let (normalized, length) = Vector3::new(1e200, 0.0, 0.0)
+            .normalize_and_length()
+            .unwrap();
+        assert_eq!((normalized, length), (Vector3::X, 1e200));
+        let (normalized, length) = Vector3::new(1e-200, 0.0, 0.0)
+            .normalize_and_length()
+            .unwrap();
+        assert_eq!((normalized, length), (Vector3::X, 1e-200));
+        assert_eq!(
+            Vector3::new(f64::MAX, f64::MAX, 0.0).normalize_and_length(),
+            Err(FastVecError::NonFinite)
+        );
+        crate::assert_vec_approx_eq!(
+            Vector3::new(f64::MAX, f64::MAX, 0.0)
+                .try_normalize()
+                .unwrap(),
+            Vector3::new(1.0, 1.0, 0.0).normalize()
+        );
+    }
+
+    #[test]
+    fn test_normalize_or() {
+        let fallback = Vector3::new(0.0, 0.0, 1.0);
+        crate::assert_vec_approx_eq!(
+            Vector3::new(3.0, 4.0, 0.0).normalize_or(fallback),
+            Vector3::new(0.6, 0.8, 0.0)
+        );
+        assert_eq!(Vector3::zeros().normalize_or(fallback), fallback);
+        assert_eq!(
+            Vector3::new(1.0, f64::NAN, 1.0).normalize_or(fallback),
+            fallback
+        );
+    }
+
+    #[test]
+    fn test_is_normalized() {
+        assert!(Vector3::new(0.6, 0.8, 0.0).is_normalized(1e-12));
+        assert!(!Vector3::new(3.0, 4.0, 0.0).is_normalized(1e-12));
+        assert!(!Vector3::new(1.0, f64::NAN, 1.0).is_normalized(1e-12));
+    }
+
+    #[test]
+    fn test_checked_div() {
+        assert_eq!(
+            Vector3::new(3.0, 4.0, 0.0).checked_div(2.0),
+            Ok(Vector3::new(1.5, 2.0, 0.0))
+        );
+        assert_eq!(
+            Vector3::new(3.0, 4.0, 0.0).checked_div(0.0),
+            Err(FastVecError::DivisionByZero)
+        );
+        assert_eq!(
+            Vector3::new(3.0, 4.0, 0.0).checked_div(1e-320),
+            Err(FastVecError::NonFinite)
+        );
+        assert_eq!(
+            Vector3::new(1.0, f64::NAN, 1.0).checked_div(2.0),
+            Err(FastVecError::NonFinite)
+        );
+    }
+
+    #[test]
+    fn test_any_orthogonal() {
+        for v in [
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(0.0, 1.0, 0.0),
+            Vector3::new(0.0, 0.0, 1.0),
+            Vector3::new(1.0, 2.0, 3.0),
+            Vector3::new(-3.0, 0.5, 0.1),
+        ] {
+            let o = v.any_orthogonal();
+            assert!(o.magnitude() > 0.0);
+            assert!(v.dot(o).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_orthonormal_basis() {
+        for v in [
+            Vector3::new(0.0, 0.0, 1.0),
+            Vector3::new(0.0, 0.0, -1.0),
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(1.0, 2.0, 3.0).normalize(),
+            Vector3::new(-1.0, 0.5, -1e-9).normalize(),
+        ] {
+            let (b1, b2) = v.orthonormal_basis();
+            assert!(b1.is_normalized(1e-12));
+            assert!(b2.is_normalized(1e-12));
+            assert!(v.dot(b1).abs() < 1e-12);
+            assert!(v.dot(b2).abs() < 1e-12);
+            assert!(b1.dot(b2).abs() < 1e-12);
+            crate::assert_vec_approx_eq!(b1.cross(b2), v, 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_gram_schmidt() {
+        let [e1, e2, e3] = Vector3::gram_schmidt([
+            Vector3::new(1.0, 1.0, 0.0),
+            Vector3::new(1.0, 0.0, 1.0),
+            Vector3::new(0.0, 1.0, 1.0),
+        ])
+        .unwrap();
+        for (a, b) in [(e1, e2), (e1, e3), (e2, e3)] {
+            assert!(a.dot(b).abs() < 1e-12);
+        }
+        for e in [e1, e2, e3] {
+            assert!(e.is_normalized(1e-12));
+        }
+        crate::assert_vec_approx_eq!(e1, Vector3::new(1.0, 1.0, 0.0).normalize());
+
+        let [e1, e2] =
+            Vector3::gram_schmidt([Vector3::new(2.0, 0.0, 0.0), Vector3::new(3.0, 4.0, 0.0)])
+                .unwrap();
+        assert_eq!(e1, Vector3::new(1.0, 0.0, 0.0));
+        crate::assert_vec_approx_eq!(e2, Vector3::new(0.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn test_gram_schmidt_degenerate() {
+        assert_eq!(
+            Vector3::gram_schmidt([Vector3::new(1.0, 2.0, 3.0), Vector3::new(2.0, 4.0, 6.0)]),
+            Err(FastVecError::LinearlyDependent)
+        );
+        assert_eq!(
+            Vector3::gram_schmidt([Vector3::zeros(), Vector3::new(1.0, 0.0, 0.0)]),
+            Err(FastVecError::LinearlyDependent)
+        );
+        assert_eq!(
+            Vector3::gram_schmidt([
+                Vector3::new(1.0, 0.0, 0.0),
+                Vector3::new(0.0, 1.0, 0.0),
+                Vector3::new(1.0, 1.0, 0.0),
+            ]),
+            Err(FastVecError::LinearlyDependent)
+        );
+        assert_eq!(
+            Vector3::gram_schmidt([Vector3::new(f64::NAN, 0.0, 0.0)]),
+            Err(FastVecError::NonFinite)
+        );
+    }
+
+    #[test]
+    fn test_gram_schmidt_extreme_lengths() {
+        assert_eq!(
+            Vector3::gram_schmidt([Vector3::new(f64::INFINITY, 0.0, 0.0)]),
+            Err(FastVecError::NonFinite)
+        );
+        let [e1, e2] =
+            Vector3::gram_schmidt([Vector3::new(1e200, 1e200, 0.0), Vector3::Z]).unwrap();
+        crate::assert_vec_approx_eq!(e1, Vector3::new(1.0, 1.0, 0.0).normalize());
+        assert_eq!(e2, Vector3::Z);
+        let [e1, e2] = Vector3::gram_schmidt([Vector3::new(1e-200, 0.0, 0.0), Vector3::Y]).unwrap();
+        assert_eq!(e1, Vector3::X);
+        assert_eq!(e2, Vector3::Y);
+    }
+
+    #[test]
+    fn test_constants() {
+        assert_eq!(Vector3::ZERO, Vector3::zeros());
+        assert_eq!(Vector3::ONE, Vector3::ones());
+        assert_eq!(Vector3::X.cross(Vector3::Y), Vector3::Z);
+        assert_eq!(Vector3::NEG_X, -Vector3::X);
+        let neg_zero = Vector3::new(-0.0, -0.0, -0.0);
+        assert_eq!(
+            (-Vector3::ZERO).dot(Vector3::X).to_bits(),
+            neg_zero.dot(Vector3::X).to_bits()
+        );
+        assert_eq!(Vector3::NEG_Y, -Vector3::Y);
+        assert_eq!(Vector3::NEG_Z, -Vector3::Z);
+        assert!(Vector3::NAN.iter().all(f64::is_nan));
+        assert!(Vector3::INFINITY.iter().all(|c| c == f64::INFINITY));
+        assert!(Vector3::NEG_INFINITY.iter().all(|c| c == f64::NEG_INFINITY));
+        assert_eq!(Vector3::MIN.z(), f64::MIN);
+        assert_eq!(Vector3::MAX.z(), f64::MAX);
+    }
+
+    #[test]
+    fn test_const_arithmetic() {
+        const DIRECTIONS: [Vector3; 4] = [
+            Vector3::X,
+            Vector3::Y.const_mul(2.0),
+            Vector3::X.const_add(Vector3::Z).const_div(2.0),
+            Vector3::ONE.const_sub(Vector3::X).const_neg(),
+        ];
+        const UP: Vector3 = Vector3::X.const_cross(Vector3::Y);
+        const DOT: f64 = Vector3::new(1.0, 2.0, 3.0).const_dot(Vector3::new(4.0, 5.0, 6.0));
+        assert_eq!(DIRECTIONS[1], Vector3::new(0.0, 2.0, 0.0));
+        assert_eq!(DIRECTIONS[2], Vector3::new(0.5, 0.0, 0.5));
+        assert_eq!(DIRECTIONS[3], Vector3::new(0.0, -1.0, -1.0));
+        assert_eq!(UP, Vector3::Z);
+        assert_eq!(DOT, 32.0);
+
+        let a = Vector3::new(1.5, -2.0, 0.75);
+        let b = Vector3::new(0.25, 3.0, -8.0);
+        assert_eq!(a.const_add(b), a + b);
+        assert_eq!(a.const_sub(b), a - b);
+        assert_eq!(a.const_mul(3.0), a * 3.0);
+        assert_eq!(a.const_div(4.0), a / 4.0);
+        assert_eq!(a.const_neg(), -a);
+        assert_eq!(a.const_dot(b), a.dot(b));
+        for (a, b) in [
+            (Vector3::new(1e16, 1.0, -1e16), Vector3::ONE),
+            (Vector3::new(0.1, 0.2, 0.3), Vector3::new(0.7, -1.3, 2.9)),
+            (Vector3::ZERO, -Vector3::ONE),
+            (Vector3::ZERO, Vector3::NEG_X),
+            (-Vector3::ZERO, Vector3::X),
+            (Vector3::NAN, Vector3::ONE),
+        ] {
+            assert_eq!(a.const_dot(b).to_bits(), a.dot(b).to_bits());
+        }
+        assert_eq!(a.const_cross(b), a.cross(b));
+    }
+
+    const SUBNORMAL: f64 = f64::MIN_POSITIVE / 4.0;
+
+    const SPECIAL_VALUES: [f64; 8] = [
+        0.0,
+        -0.0,
+        1.5,
+        SUBNORMAL,
+        f64::MAX,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NAN,
+    ];
+
+    fn special_vectors() -> Vec<Vector3> {
+        SPECIAL_VALUES
+            .iter()
+            .flat_map(|&s| {
+                [
+                    Vector3::new(s, 0.0, 0.0),
+                    Vector3::new(0.0, s, 1.0),
+                    Vector3::new(s, s, s),
+                ]
+            })
+            .collect()
+    }
+
+    fn assert_padding(v: Vector3) {
+        assert_eq!(v.0[3], 0.0, "padding lane of {v:?}");
+    }
+
+    #[test]
+    fn test_padding_lane_with_special_values() {
+        let vectors = special_vectors();
+        for &a in &vectors {
+            assert_padding(-a);
+            assert_padding(a.normalize());
+            assert_padding(a.normalize_or(Vector3::X));
+            assert_padding(a.any_orthogonal());
+            let (b1, b2) = a.orthonormal_basis();
+            assert_padding(b1);
+            assert_padding(b2);
+            for &b in &vectors {
+                assert_padding(a + b);
+                assert_padding(a - b);
+                assert_padding(a.cross(b));
+                let mut assigned = a;
+                assigned += b;
+                assigned -= b;
+                assert_padding(assigned);
+            }
+            for s in SPECIAL_VALUES {
+                assert_padding(a * s);
+                assert_padding(s * a);
+                assert_padding(a / s);
+                assert_padding(a.div_exact(s));
+                assert_padding(a.div_fast(s));
+                let mut assigned = a;
+                assigned *= s;
+                assigned /= s;
+                assert_padding(assigned);
+            }
+        }
+    }
+
+    #[test]
+    fn test_infinity_does_not_become_nan() {
+        // Components that are 0 * inf are NaN by IEEE rules, so these avoid zero components
+        // and check that the padding lane does not add a NaN of its own.
+        let inf = Vector3::ONE * f64::INFINITY;
+        assert_eq!(inf.magnitude_squared(), f64::INFINITY);
+        assert_eq!(inf.magnitude(), f64::INFINITY);
+        assert_eq!(inf.dot(Vector3::ONE), f64::INFINITY);
+        assert_eq!((Vector3::ONE / 0.0).magnitude(), f64::INFINITY);
+        assert_eq!(Vector3::ONE.div_fast(0.0).magnitude(), f64::INFINITY);
+        let mut assigned = Vector3::new(-1.0, 2.0, 3.0);
+        assigned *= f64::INFINITY;
+        assert_eq!(assigned.magnitude(), f64::INFINITY);
+        assert_eq!(Vector3::NEG_INFINITY.magnitude(), f64::INFINITY);
+        assert_eq!(inf.distance(Vector3::ZERO), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_nan_stays_in_its_component() {
+        let v = Vector3::new(f64::NAN, 1.0, 2.0);
+        let scaled = v * 2.0;
+        assert!(scaled.x().is_nan());
+        assert_eq!((scaled.y(), scaled.z()), (2.0, 4.0));
+        assert!(v.magnitude().is_nan());
+        assert!(!v.is_finite());
+        let cross = Vector3::Y.cross(Vector3::new(0.0, 0.0, f64::NAN));
+        assert!(cross.x().is_nan());
+        assert_eq!(cross.z(), 0.0);
+    }
+
+    #[test]
+    fn test_subnormal_components() {
+        let v = Vector3::new(SUBNORMAL, -SUBNORMAL, SUBNORMAL);
+        assert!(v.x().is_subnormal());
+        assert_eq!(v * 1.0, v);
+        assert_eq!(v / 1.0, v);
+        assert_eq!((v * 2.0).x(), SUBNORMAL * 2.0);
+        assert_eq!(v.dot(Vector3::ONE), SUBNORMAL);
+        assert_eq!(v.magnitude_squared(), 0.0);
+        assert_eq!(v + v, v * 2.0);
+        assert_eq!(v - v, Vector3::ZERO);
+        assert!(v.is_finite());
+    }
+
+    /// Compares bits, except that any NaN matches any NaN: `Display` and the division
+    /// policies do not preserve NaN payloads or signs.
+    fn assert_bit_exact(parsed: Vector3, original: Vector3) {
+        for (a, b) in parsed.iter().zip(original.iter()) {
+            if b.is_nan() {
+                assert!(a.is_nan(), "expected NaN, got {a}");
+            } else {
+                assert_eq!(a.to_bits(), b.to_bits());
+            }
+        }
+    }
+
+    #[test]
+    fn test_nan_round_trip_is_canonical() {
+        let nan = f64::from_bits(0xfff8_0000_dead_beef);
+        let v = Vector3::from_array([nan; 3]);
+        let parsed: Vector3 = v.to_string().parse().unwrap();
+        for component in parsed.iter() {
+            assert_eq!(component.to_bits(), f64::NAN.to_bits());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn prop_display_round_trip(x in any::<f64>(), y in any::<f64>(), z in any::<f64>()) {
+            let v = Vector3::new(x, y, z);
+            assert_bit_exact(v.to_string().parse().unwrap(), v);
+        }
+
+        #[test]
+        fn prop_exp_round_trip(x in any::<f64>(), y in any::<f64>(), z in any::<f64>()) {
+            let v = Vector3::new(x, y, z);
+            assert_bit_exact(format!("{v:e}").parse().unwrap(), v);
+            assert_bit_exact(format!("{v:E}").parse().unwrap(), v);
+        }
+
+        #[test]
+        fn prop_div_matches_div_assign(
+            x in any::<f64>(),
+            y in any::<f64>(),
+            z in any::<f64>(),
+            s in any::<f64>(),
+        ) {
+            let v = Vector3::new(x, y, z);
+            let mut assigned = v;
+            assigned /= s;
+            assert_bit_exact(assigned, v / s);
+            let policy = if cfg!(feature = "fast-math") { v.div_fast(s) } else { v.div_exact(s) };
+            assert_bit_exact(v / s, policy);
+        }
+    }
 }